@@ -1,17 +1,56 @@
+use bech32::FromBase32;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
-use cw2::set_contract_version;
+use cosmwasm_std::{
+    coin, from_slice, to_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, Decimal, Deps,
+    DepsMut, Empty, Env, MessageInfo, Order, Response, StdError, StdResult, Storage, Timestamp,
+    Uint128,
+};
+use cw2::{get_contract_version, set_contract_version};
 use cw_storage_plus::Bound;
+use ripemd160::Ripemd160;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, TemplateResponse, TierlistResponse};
+use crate::export::{encode_template, encode_tierlist};
+use crate::msg::{
+    AggregateRankingsResponse, AggregatedItem, AgreementScoreResponse, BannedAddressesResponse,
+    BootstrapResponse, CategoriesResponse, CompareTierlistsResponse, ConfigResponse,
+    ControversialItem, ControversialItemsResponse, ExecuteMsg, ExternalTemplateResponse,
+    HasTierlistResponse, InstantiateMsg, ItemDistributionResponse, MigrateMsg, MostSimilarResponse,
+    NonceResponse, ParticipantCountResponse, PinnedTierlistsResponse, QueryMsg, SavePermit,
+    SearchTemplatesResponse, TemplateCursor, TemplateFeeResponse, TemplateForksResponse,
+    TemplateResponse, TemplateSort, TemplateSummary, TemplateSummaryResponse,
+    TemplateWithCountResponse, TemplatesByItemCountResponse, TierlistByTiersResponse,
+    TierlistFingerprintResponse, TierlistResponse, TierlistWithTemplate,
+    TierlistWithTemplateResponse, TierlistsForTemplateResponse, TierlistsWithCommentsResponse,
+    WeightedConsensusResponse, WeightedItem,
+};
+use crate::ownership::{
+    assert_owner, get_ownership, initialize_owner, is_owner, update_ownership, Action, OWNERSHIP,
+};
 use crate::state::{
-    Config, Tierlist, TierlistItem, TierlistTemplate, CONFIG, NEXT_ID, TIERLISTS,
-    TIERLIST_TEMPLATES,
+    allocate_template_id, compare_tierlists, load_template_or_err, load_tierlist_or_err,
+    tier_spread, Config, ExternalTemplateRef, TemplateVisibility, TierDef, Tierlist, TierlistItem,
+    TierlistTemplate, ARCHIVED_TEMPLATES, BANNED_ADDRESSES, CATEGORIES, CONFIG, DEFAULT_CATEGORIES,
+    DEFAULT_MAX_TIERLIST_BYTES, DEFAULT_TIERS, EXTERNAL_TEMPLATES, LEGACY_CONFIG, MAX_ALT_NAMES,
+    MAX_ALT_NAME_LENGTH, MAX_BATCH_SAVES, MAX_COMMENT_LENGTH, MAX_DEFAULT_TIERS,
+    MAX_DEFAULT_TIER_LENGTH, MAX_EXTERNAL_ID_LENGTH, MAX_HISTORY_SNAPSHOTS,
+    MAX_IDEMPOTENCY_KEY_LENGTH, MAX_INITIAL_COLLABORATORS, MAX_METADATA_URL_LENGTH,
+    MAX_PINNED_TIERLISTS, MAX_SLUG_LENGTH, MAX_TEMPLATE_ITEMS, MAX_TIER_DEFINITIONS,
+    MAX_TIER_EMOJI_BYTES, METADATA_URL_SCHEMES, MIGRATION_STATUS, MIN_TIER_DEFINITIONS, NEXT_ID,
+    NONCES, PINNED_TIERLISTS, PUBLIC_TIERLISTS, SAVE_IDEMPOTENCY_KEYS, TEMPLATES_BY_CATEGORY,
+    TEMPLATES_BY_CREATED_AT, TEMPLATES_BY_CREATOR, TEMPLATES_BY_ITEM, TEMPLATES_BY_LANGUAGE,
+    TEMPLATES_BY_SLUG, TEMPLATE_COLLABORATORS, TEMPLATE_FEES, TEMPLATE_FORKS, TEMPLATE_SLUGS,
+    TEMPLATE_TIERLIST_COUNT, TIERLISTS, TIERLISTS_BY_TEMPLATE_INDEX, TIERLISTS_WITH_COMMENTS_INDEX,
+    TIERLIST_COMMENTS, TIERLIST_HISTORY, TIERLIST_REVISION_COUNT, TIERLIST_TEMPLATES,
+    USER_COLLABORATING,
 };
 
 const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:cw-tierlist";
@@ -26,11 +65,16 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    deps.api.addr_validate(&msg.admin_address)?;
+    initialize_owner(deps.storage, deps.api, Some(&msg.admin_address))?;
     let config = Config {
-        admin_address: msg.admin_address.clone(),
+        require_template_tiers: msg.require_template_tiers,
+        hide_creators: msg.hide_creators,
+        max_tierlist_bytes: msg.max_tierlist_bytes.unwrap_or(DEFAULT_MAX_TIERLIST_BYTES),
+        min_items_per_template: None,
     };
     CONFIG.save(deps.storage, &config)?;
+    let categories = DEFAULT_CATEGORIES.iter().map(|c| c.to_string()).collect();
+    CATEGORIES.save(deps.storage, &categories)?;
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
@@ -45,35 +89,599 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::CreateTemplate { title, items } => {
-            execute_create_template(deps, env, info, title, items)
+        ExecuteMsg::CreateTemplate {
+            title,
+            items,
+            tier_definitions,
+            max_submissions,
+            language,
+            strict_uniqueness,
+            visibility,
+        } => execute_create_template(
+            deps,
+            env,
+            info,
+            title,
+            items,
+            tier_definitions,
+            max_submissions,
+            language,
+            strict_uniqueness.unwrap_or(false),
+            visibility.unwrap_or_default(),
+        ),
+        ExecuteMsg::CreateTemplateWithFee {
+            title,
+            items,
+            tier_definitions,
+            max_submissions,
+            language,
+            fee_token,
+            fee_amount,
+            visibility,
+        } => execute_create_template_with_fee(
+            deps,
+            env,
+            info,
+            title,
+            items,
+            tier_definitions,
+            max_submissions,
+            language,
+            coin(fee_amount.u128(), fee_token),
+            visibility.unwrap_or_default(),
+        ),
+        ExecuteMsg::CreateTemplateWithCollaborators {
+            title,
+            items,
+            collaborators,
+            visibility,
+        } => execute_create_template_with_collaborators(
+            deps,
+            env,
+            info,
+            title,
+            items,
+            collaborators,
+            visibility.unwrap_or_default(),
+        ),
+        ExecuteMsg::CreateAnonymousTemplate {
+            title,
+            items,
+            visibility,
+        } => execute_create_anonymous_template(
+            deps,
+            env,
+            info,
+            title,
+            items,
+            visibility.unwrap_or_default(),
+        ),
+        ExecuteMsg::ImportTemplateFromJSON { json, visibility } => {
+            execute_import_template_from_json(deps, env, info, json, visibility.unwrap_or_default())
+        }
+        ExecuteMsg::CreateTemplateFromRemote {
+            title,
+            contract,
+            template_id,
+            visibility,
+        } => execute_create_template_from_remote(
+            deps,
+            env,
+            info,
+            title,
+            contract,
+            template_id,
+            visibility.unwrap_or_default(),
+        ),
+        ExecuteMsg::CloneTemplate { id, visibility } => {
+            execute_clone_template(deps, env, info, id, visibility)
+        }
+        ExecuteMsg::RemoveTemplateFee { template_id } => {
+            execute_remove_template_fee(deps, env, info, template_id)
         }
         ExecuteMsg::DeleteTemplate { id } => execute_delete_template(deps, env, info, id),
-        ExecuteMsg::EditTemplate { id, title, items } => {
-            execute_edit_template(deps, env, info, id, title, items)
+        ExecuteMsg::EditTemplate {
+            id,
+            title,
+            items,
+            tier_definitions,
+            max_submissions,
+            language,
+            visibility,
+        } => execute_edit_template(
+            deps,
+            env,
+            info,
+            id,
+            title,
+            items,
+            tier_definitions,
+            max_submissions,
+            language,
+            visibility,
+        ),
+        ExecuteMsg::PatchTemplate { id, title, items } => {
+            execute_patch_template(deps, env, info, id, title, items)
+        }
+        ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace,
+            idempotency_key,
+            force,
+        } => execute_save_tierlist(
+            deps,
+            env,
+            info,
+            tierlist,
+            replace.unwrap_or(true),
+            idempotency_key,
+            force.unwrap_or(false),
+        ),
+        ExecuteMsg::SaveTierlists {
+            tierlists,
+            best_effort,
+        } => execute_save_tierlists(deps, env, info, tierlists, best_effort.unwrap_or(false)),
+        ExecuteMsg::SaveTierlistFor {
+            owner,
+            tierlist,
+            signature,
+            pubkey,
+            nonce,
+        } => execute_save_tierlist_for(deps, env, info, owner, tierlist, signature, pubkey, nonce),
+        ExecuteMsg::SaveTierlistAndComment { tierlist, comment } => {
+            execute_save_tierlist_and_comment(deps, env, info, tierlist, comment)
+        }
+        ExecuteMsg::ReorderTemplateItems { id, order } => {
+            execute_reorder_template_items(deps, env, info, id, order)
+        }
+        ExecuteMsg::AdminDeleteTierlist {
+            address,
+            template_id,
+        } => execute_admin_delete_tierlist(deps, env, info, address, template_id),
+        ExecuteMsg::AdminRedactTierlist {
+            address,
+            template_id,
+        } => execute_admin_redact_tierlist(deps, env, info, address, template_id),
+        ExecuteMsg::ClearTierlistHistory {
+            template_id,
+            keep_latest,
+        } => execute_clear_tierlist_history(deps, env, info, template_id, keep_latest),
+        ExecuteMsg::AdminClearTierlistHistory {
+            address,
+            template_id,
+            keep_latest,
+        } => {
+            execute_admin_clear_tierlist_history(deps, env, info, address, template_id, keep_latest)
+        }
+        ExecuteMsg::UpdateConfig {
+            require_template_tiers,
+            hide_creators,
+            max_tierlist_bytes,
+            min_items_per_template,
+        } => execute_update_config(
+            deps,
+            env,
+            info,
+            require_template_tiers,
+            hide_creators,
+            max_tierlist_bytes,
+            min_items_per_template,
+        ),
+        ExecuteMsg::SetDefaultTiers { tiers } => execute_set_default_tiers(deps, env, info, tiers),
+        ExecuteMsg::BanAddress { address } => execute_ban_address(deps, env, info, address),
+        ExecuteMsg::UnbanAddress { address } => execute_unban_address(deps, env, info, address),
+        ExecuteMsg::PinTierlist {
+            address,
+            template_id,
+        } => execute_pin_tierlist(deps, env, info, address, template_id),
+        ExecuteMsg::UnpinTierlist {
+            address,
+            template_id,
+        } => execute_unpin_tierlist(deps, env, info, address, template_id),
+        ExecuteMsg::SealTemplate { id } => execute_seal_template(deps, env, info, id),
+        ExecuteMsg::CopyRankings {
+            from_template_id,
+            to_template_id,
+        } => execute_copy_rankings(deps, env, info, from_template_id, to_template_id),
+        ExecuteMsg::PublishTierlist { template_id } => {
+            execute_publish_tierlist(deps, env, info, template_id)
+        }
+        ExecuteMsg::UnpublishTierlist { template_id } => {
+            execute_unpublish_tierlist(deps, env, info, template_id)
+        }
+        ExecuteMsg::SetItemOrder {
+            template_id,
+            orders,
+        } => execute_set_item_order(deps, env, info, template_id, orders),
+        ExecuteMsg::AddTierDefinition {
+            template_id,
+            tier_name,
+        } => execute_add_tier_definition(deps, env, info, template_id, tier_name),
+        ExecuteMsg::RemoveTierDefinition {
+            template_id,
+            tier_name,
+        } => execute_remove_tier_definition(deps, env, info, template_id, tier_name),
+        ExecuteMsg::SetSubmissionsOpen { id, open } => {
+            execute_set_submissions_open(deps, env, info, id, open)
+        }
+        ExecuteMsg::SetTemplateExpiry { id, expires_at } => {
+            execute_set_template_expiry(deps, env, info, id, expires_at)
+        }
+        ExecuteMsg::ArchiveTemplate { id } => execute_set_archived(deps, env, info, id, true),
+        ExecuteMsg::RestoreTemplate { id } => execute_set_archived(deps, env, info, id, false),
+        ExecuteMsg::SetTemplateCategory { id, category } => {
+            execute_set_template_category(deps, env, info, id, category)
+        }
+        ExecuteMsg::SetTemplateSlug { id, slug } => {
+            execute_set_template_slug(deps, env, info, id, slug)
+        }
+        ExecuteMsg::UpdateCategories { add, remove } => {
+            execute_update_categories(deps, env, info, add, remove)
+        }
+        ExecuteMsg::LockTierlist { template_id, owner } => {
+            execute_set_tierlist_locked(deps, env, info, template_id, owner, true)
+        }
+        ExecuteMsg::UnlockTierlist { template_id, owner } => {
+            execute_set_tierlist_locked(deps, env, info, template_id, owner, false)
+        }
+        ExecuteMsg::SyncTierlist { template_id } => {
+            execute_sync_tierlist(deps, env, info, template_id)
+        }
+        ExecuteMsg::PurgeTemplateTierlists { template_id, limit } => {
+            execute_purge_template_tierlists(deps, env, info, template_id, limit)
         }
-        ExecuteMsg::SaveTierlist { tierlist } => execute_save_tierlist(deps, env, info, tierlist),
+        ExecuteMsg::RegisterExternalTemplate {
+            external_id,
+            metadata_url,
+        } => execute_register_external_template(deps, env, info, external_id, metadata_url),
+        ExecuteMsg::UpdateOwnership(action) => execute_update_ownership(deps, env, info, action),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_create_template(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    items: Vec<TierlistItem>,
+    tier_definitions: Option<Vec<TierDef>>,
+    max_submissions: Option<u32>,
+    language: Option<String>,
+    strict_uniqueness: bool,
+    visibility: TemplateVisibility,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    create_template(
+        deps,
+        &env,
+        info.sender,
+        title,
+        items,
+        tier_definitions,
+        max_submissions,
+        language,
+        strict_uniqueness,
+        visibility,
+    )?;
+    Ok(Response::new())
+}
+
+pub fn execute_create_template_with_collaborators(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    items: Vec<TierlistItem>,
+    collaborators: Vec<String>,
+    visibility: TemplateVisibility,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    if collaborators.len() > MAX_INITIAL_COLLABORATORS {
+        return Err(ContractError::TooManyCollaborators {
+            max: MAX_INITIAL_COLLABORATORS,
+        });
+    }
+    let collaborators = collaborators
+        .iter()
+        .map(|address| normalize_addr(deps.api, address))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let id = create_template(
+        deps.branch(),
+        &env,
+        info.sender,
+        title,
+        items,
+        None,
+        None,
+        None,
+        false,
+        visibility,
+    )?;
+    for collaborator in &collaborators {
+        TEMPLATE_COLLABORATORS.save(deps.storage, (id, collaborator), &Empty {})?;
+        USER_COLLABORATING.save(deps.storage, (collaborator, id), &Empty {})?;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "create_template_with_collaborators")
+        .add_attribute("template_id", id.to_string())
+        .add_attribute("collaborators", collaborators.len().to_string()))
+}
+
+pub fn execute_create_anonymous_template(
+    deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     title: String,
     items: Vec<TierlistItem>,
+    visibility: TemplateVisibility,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let id = create_template(
+        deps,
+        &env,
+        Addr::unchecked(""),
+        title,
+        items,
+        None,
+        None,
+        None,
+        false,
+        visibility,
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "create_anonymous_template")
+        .add_attribute("template_id", id.to_string()))
+}
+
+/// The parts of a `TierlistTemplate` JSON document an import uses, other fields are ignored
+#[derive(Deserialize)]
+struct ImportedTemplate {
+    title: String,
+    items: Vec<TierlistItem>,
+    tier_definitions: Option<Vec<TierDef>>,
+    max_submissions: Option<u32>,
+    language: Option<String>,
+    #[serde(default)]
+    strict_uniqueness: bool,
+}
+
+pub fn execute_import_template_from_json(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    json: String,
+    visibility: TemplateVisibility,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let imported: ImportedTemplate =
+        from_slice(json.as_bytes()).map_err(|err| ContractError::InvalidTemplateJson {
+            reason: err.to_string(),
+        })?;
+    let id = create_template(
+        deps,
+        &env,
+        info.sender,
+        imported.title,
+        imported.items,
+        imported.tier_definitions,
+        imported.max_submissions,
+        imported.language,
+        imported.strict_uniqueness,
+        visibility,
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "import_template_from_json")
+        .add_attribute("template_id", id.to_string()))
+}
+
+pub fn execute_create_template_from_remote(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    contract: String,
+    template_id: u64,
+    visibility: TemplateVisibility,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let contract = deps.api.addr_validate(&contract)?;
+    let msg = QueryMsg::Template {
+        id: template_id,
+        strict: None,
+    };
+    let res: TemplateResponse = deps
+        .querier
+        .query_wasm_smart(&contract, &msg)
+        .map_err(|err| ContractError::InvalidRemoteTemplate {
+            contract: contract.to_string(),
+            reason: err.to_string(),
+        })?;
+    let remote = res
+        .template
+        .ok_or_else(|| ContractError::RemoteTemplateNotFound {
+            contract: contract.to_string(),
+            id: template_id,
+        })?;
+    let id = create_template(
+        deps.branch(),
+        &env,
+        info.sender,
+        title,
+        remote.items,
+        None,
+        None,
+        None,
+        false,
+        visibility,
+    )?;
+    TIERLIST_TEMPLATES.update(deps.storage, id, |template| -> StdResult<_> {
+        let mut template = template.ok_or_else(|| StdError::not_found("TierlistTemplate"))?;
+        template.source = Some((contract.to_string(), template_id));
+        Ok(template)
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "create_template_from_remote")
+        .add_attribute("template_id", id.to_string())
+        .add_attribute("source_contract", contract)
+        .add_attribute("source_template_id", template_id.to_string()))
+}
+
+pub fn execute_clone_template(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    visibility: Option<TemplateVisibility>,
 ) -> Result<Response, ContractError> {
-    let id = NEXT_ID.may_load(deps.storage)?.unwrap_or_default();
-    NEXT_ID.save(deps.storage, &(id + 1))?;
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let source = load_template_or_err(deps.storage, id)?;
+    let fork_id = create_template(
+        deps.branch(),
+        &env,
+        info.sender,
+        source.title,
+        source.items,
+        source.tier_definitions,
+        source.max_submissions,
+        source.language,
+        source.strict_uniqueness,
+        visibility.unwrap_or(source.visibility),
+    )?;
+    TIERLIST_TEMPLATES.update(deps.storage, fork_id, |fork| -> StdResult<_> {
+        let mut fork = fork.ok_or_else(|| StdError::not_found("TierlistTemplate"))?;
+        fork.forked_from = Some(id);
+        Ok(fork)
+    })?;
+    TEMPLATE_FORKS.save(deps.storage, (id, fork_id), &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "clone_template")
+        .add_attribute("template_id", id.to_string())
+        .add_attribute("fork_id", fork_id.to_string()))
+}
 
-    let template = TierlistTemplate {
+/// Validates and stores a new template, returning its id
+#[allow(clippy::too_many_arguments)]
+fn create_template(
+    deps: DepsMut,
+    env: &Env,
+    creator: Addr,
+    title: String,
+    items: Vec<TierlistItem>,
+    tier_definitions: Option<Vec<TierDef>>,
+    max_submissions: Option<u32>,
+    language: Option<String>,
+    strict_uniqueness: bool,
+    visibility: TemplateVisibility,
+) -> Result<u64, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    validate_items(&items, strict_uniqueness)?;
+    validate_min_items(&config, &items)?;
+    validate_tier_definitions(&config, &tier_definitions)?;
+    validate_alt_names(&items)?;
+    validate_language(&language)?;
+
+    let id = allocate_template_id(deps.storage)?;
+
+    let mut template = TierlistTemplate {
         id,
         title,
         items,
-        creator: info.sender.to_string(),
+        creator,
+        tier_definitions,
+        max_submissions,
+        submissions_open: true,
+        sealed_at: None,
+        expires_at: None,
+        category: None,
+        language,
+        archived: false,
+        forked_from: None,
+        source: None,
+        created_at: Some(env.block.time),
+        strict_uniqueness,
+        visibility,
+        content_hash: Binary::default(),
     };
+    template.refresh_content_hash();
     TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
-    Ok(Response::new())
+    TEMPLATES_BY_CREATOR.save(deps.storage, (&template.creator, id), &Empty {})?;
+    TEMPLATES_BY_CREATED_AT.save(deps.storage, (env.block.time.seconds(), id), &Empty {})?;
+    update_item_index(deps.storage, id, &[], &template.items)?;
+    if let Some(language) = &template.language {
+        TEMPLATES_BY_LANGUAGE.save(deps.storage, (language, id), &Empty {})?;
+    }
+    Ok(id)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_create_template_with_fee(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    items: Vec<TierlistItem>,
+    tier_definitions: Option<Vec<TierDef>>,
+    max_submissions: Option<u32>,
+    language: Option<String>,
+    fee: Coin,
+    visibility: TemplateVisibility,
+) -> Result<Response, ContractError> {
+    if fee.amount.is_zero() || fee.denom.is_empty() {
+        return Err(ContractError::InvalidTemplateFee {});
+    }
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let id = create_template(
+        deps.branch(),
+        &env,
+        info.sender,
+        title,
+        items,
+        tier_definitions,
+        max_submissions,
+        language,
+        false,
+        visibility,
+    )?;
+    TEMPLATE_FEES.save(deps.storage, id, &fee)?;
+    Ok(Response::new()
+        .add_attribute("action", "create_template_with_fee")
+        .add_attribute("template_id", id.to_string())
+        .add_attribute("fee", fee.to_string()))
+}
+
+pub fn execute_remove_template_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    let template = load_template_or_err(deps.storage, template_id)?;
+    if info.sender != template.creator && !is_owner(deps.storage, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator or admin may remove the fee".to_string(),
+        });
+    }
+
+    TEMPLATE_FEES.remove(deps.storage, template_id);
+    Ok(Response::new()
+        .add_attribute("action", "remove_template_fee")
+        .add_attribute("template_id", template_id.to_string()))
 }
 
 pub fn execute_delete_template(
@@ -82,16 +690,48 @@ pub fn execute_delete_template(
     info: MessageInfo,
     id: u64,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    let template = TIERLIST_TEMPLATES.load(deps.storage, id)?;
-    if info.sender != template.creator && info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {});
+    let template = load_template_or_err(deps.storage, id)?;
+    if !is_owner(deps.storage, &info.sender)?
+        && (template.is_anonymous() || info.sender != template.creator)
+    {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator or admin may delete templates".to_string(),
+        });
     }
 
     TIERLIST_TEMPLATES.remove(deps.storage, id);
+    TEMPLATES_BY_CREATOR.remove(deps.storage, (&template.creator, id));
+    TEMPLATE_FEES.remove(deps.storage, id);
+    ARCHIVED_TEMPLATES.remove(deps.storage, id);
+    if let Some(created_at) = template.created_at {
+        TEMPLATES_BY_CREATED_AT.remove(deps.storage, (created_at.seconds(), id));
+    }
+    update_item_index(deps.storage, id, &template.items, &[])?;
+    let collaborators = TEMPLATE_COLLABORATORS
+        .prefix(id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for collaborator in &collaborators {
+        TEMPLATE_COLLABORATORS.remove(deps.storage, (id, collaborator));
+        USER_COLLABORATING.remove(deps.storage, (collaborator, id));
+    }
+    if let Some(source) = template.forked_from {
+        TEMPLATE_FORKS.remove(deps.storage, (source, id));
+    }
+    if let Some(category) = &template.category {
+        TEMPLATES_BY_CATEGORY.remove(deps.storage, (category, id));
+    }
+    if let Some(language) = &template.language {
+        TEMPLATES_BY_LANGUAGE.remove(deps.storage, (language, id));
+    }
+    if let Some(slug) = TEMPLATE_SLUGS.may_load(deps.storage, id)? {
+        TEMPLATES_BY_SLUG.remove(deps.storage, &slug);
+        TEMPLATE_SLUGS.remove(deps.storage, id);
+    }
     Ok(Response::new())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_edit_template(
     deps: DepsMut,
     _env: Env,
@@ -99,153 +739,10913 @@ pub fn execute_edit_template(
     id: u64,
     title: String,
     items: Vec<TierlistItem>,
+    tier_definitions: Option<Vec<TierDef>>,
+    max_submissions: Option<u32>,
+    language: Option<String>,
+    visibility: Option<TemplateVisibility>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let existing_template = TIERLIST_TEMPLATES.load(deps.storage, id)?;
-    if info.sender != existing_template.creator && info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {});
+    let existing_template = load_template_or_err(deps.storage, id)?;
+    if !may_edit_template(deps.storage, &existing_template, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator, a collaborator or admin may edit templates"
+                .to_string(),
+        });
+    }
+    validate_items(&items, existing_template.strict_uniqueness)?;
+    validate_min_items(&config, &items)?;
+    validate_tier_definitions(&config, &tier_definitions)?;
+    validate_alt_names(&items)?;
+    validate_language(&language)?;
+
+    // Once people have submitted the limit may only be raised or removed
+    let submissions = TEMPLATE_TIERLIST_COUNT
+        .may_load(deps.storage, id)?
+        .unwrap_or_default();
+    if submissions > 0 {
+        let lowered = match (existing_template.max_submissions, max_submissions) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(old), Some(new)) => new < old,
+        };
+        if lowered {
+            return Err(ContractError::SubmissionLimitLowered {});
+        }
     }
 
-    let template = TierlistTemplate {
+    let mut template = TierlistTemplate {
         id,
         title,
         items,
         creator: existing_template.creator,
+        tier_definitions,
+        max_submissions,
+        submissions_open: existing_template.submissions_open,
+        sealed_at: existing_template.sealed_at,
+        expires_at: existing_template.expires_at,
+        category: existing_template.category,
+        language,
+        archived: existing_template.archived,
+        forked_from: existing_template.forked_from,
+        source: existing_template.source,
+        created_at: existing_template.created_at,
+        strict_uniqueness: existing_template.strict_uniqueness,
+        visibility: visibility.unwrap_or(existing_template.visibility),
+        content_hash: Binary::default(),
     };
+    template.refresh_content_hash();
     TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    if let Some(previous) = &existing_template.language {
+        TEMPLATES_BY_LANGUAGE.remove(deps.storage, (previous, id));
+    }
+    if let Some(language) = &template.language {
+        TEMPLATES_BY_LANGUAGE.save(deps.storage, (language, id), &Empty {})?;
+    }
+    update_item_index(deps.storage, id, &existing_template.items, &template.items)?;
     Ok(Response::new())
 }
 
-pub fn execute_save_tierlist(
+/// Whether `sender` may change a template's title and items
+fn may_edit_template(
+    storage: &dyn Storage,
+    template: &TierlistTemplate,
+    sender: &Addr,
+) -> StdResult<bool> {
+    Ok(is_owner(storage, sender)?
+        || (!template.is_anonymous() && *sender == template.creator)
+        || TEMPLATE_COLLABORATORS.has(storage, (template.id, sender)))
+}
+
+/// Moves a template's entries in the item name index from its old items to its new ones
+fn update_item_index(
+    storage: &mut dyn Storage,
+    id: u64,
+    old: &[TierlistItem],
+    new: &[TierlistItem],
+) -> StdResult<()> {
+    for item in old {
+        TEMPLATES_BY_ITEM.remove(storage, (&item.name, id));
+    }
+    for item in new {
+        TEMPLATES_BY_ITEM.save(storage, (&item.name, id), &Empty {})?;
+    }
+    Ok(())
+}
+
+pub fn execute_patch_template(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    tierlist: Tierlist,
+    id: u64,
+    title: Option<String>,
+    items: Option<Vec<TierlistItem>>,
 ) -> Result<Response, ContractError> {
-    let template = TIERLIST_TEMPLATES.load(deps.storage, tierlist.template_id)?;
-    let id = tierlist.template_id;
-    let valid = tierlist.clone().validate_against_template(template);
-    if !valid {
-        return Err(ContractError::InvalidTierlist {});
+    let config = CONFIG.load(deps.storage)?;
+    let mut template = load_template_or_err(deps.storage, id)?;
+    if !may_edit_template(deps.storage, &template, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator, a collaborator or admin may edit templates"
+                .to_string(),
+        });
     }
 
-    TIERLISTS.save(deps.storage, (info.sender.to_string(), id), &tierlist)?;
-    Ok(Response::new())
+    let res = Response::new()
+        .add_attribute("action", "patch_template")
+        .add_attribute("template_id", id.to_string());
+    if title.is_none() && items.is_none() {
+        return Ok(res.add_attribute("warning", "nothing to patch"));
+    }
+    if let Some(title) = title {
+        template.title = title;
+    }
+    if let Some(items) = items {
+        validate_items(&items, template.strict_uniqueness)?;
+        validate_min_items(&config, &items)?;
+        validate_alt_names(&items)?;
+        update_item_index(deps.storage, id, &template.items, &items)?;
+        template.items = items;
+    }
+    template.refresh_content_hash();
+    TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    Ok(res)
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
-        QueryMsg::Template { id } => query_template(deps, id),
-        QueryMsg::TierlistFromTemplate { id } => query_tierlist_from_template(deps, id),
-        QueryMsg::Tierlist { address, id } => query_tierlist(deps, address, id),
-        QueryMsg::TierlistsByAddress {
-            address,
-            start_after,
-            limit,
-        } => query_tierlists_by_address(deps, address, start_after, limit),
-        QueryMsg::Templates { start_after, limit } => {
-            query_tierlist_templates(deps, start_after, limit)
+pub fn execute_set_item_order(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    orders: Vec<(String, u32)>,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, template_id)?;
+    if !may_edit_template(deps.storage, &template, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator, a collaborator or admin may order items"
+                .to_string(),
+        });
+    }
+
+    // Items left out keep their order and may still share the default of 0, only the
+    // orders being assigned must be distinct from each other and from those
+    let mut taken: Vec<_> = template
+        .items
+        .iter()
+        .filter(|i| !orders.iter().any(|(name, _)| *name == i.name))
+        .map(|i| i.order)
+        .collect();
+    for (name, order) in orders {
+        if taken.contains(&order) {
+            return Err(ContractError::DuplicateItemOrder { order });
         }
+        taken.push(order);
+        let item = template
+            .items
+            .iter_mut()
+            .find(|i| i.name == name)
+            .ok_or(ContractError::ItemNotFound { name })?;
+        item.order = order;
     }
-}
 
-pub fn query_template(deps: Deps, id: u64) -> StdResult<Binary> {
-    let template = TIERLIST_TEMPLATES.may_load(deps.storage, id)?;
-    to_binary(&TemplateResponse { template })
+    template.refresh_content_hash();
+    TIERLIST_TEMPLATES.save(deps.storage, template_id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_item_order")
+        .add_attribute("template_id", template_id.to_string()))
 }
 
-pub fn query_tierlist_from_template(deps: Deps, id: u64) -> StdResult<Binary> {
-    let template = TIERLIST_TEMPLATES.may_load(deps.storage, id)?;
-    match template {
-        None => to_binary(&TierlistResponse { tierlist: None }),
-        Some(template) => to_binary(&TierlistResponse {
-            tierlist: Some(Tierlist::from_template(template)),
-        }),
+pub fn execute_add_tier_definition(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    tier_name: String,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, template_id)?;
+    if !may_edit_template(deps.storage, &template, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator, a collaborator or admin may add tiers".to_string(),
+        });
+    }
+    // An empty label marks an unassigned item, so it cannot name a tier
+    if tier_name.is_empty() {
+        return Err(ContractError::InvalidTierLabel {
+            label: tier_name,
+            template_id,
+        });
     }
-}
 
-pub fn query_tierlist(deps: Deps, address: String, id: u64) -> StdResult<Binary> {
-    deps.api.addr_validate(&address).unwrap(); // Validate address
-    let tierlist = TIERLISTS.may_load(deps.storage, (address, id))?;
-    match tierlist {
-        None => to_binary(&TierlistResponse { tierlist: None }),
-        Some(tierlist) => to_binary(&TierlistResponse {
-            tierlist: Some(tierlist),
-        }),
+    let tiers = template.tier_definitions.get_or_insert_with(Vec::new);
+    if tiers.iter().any(|t| t.name == tier_name) {
+        return Err(ContractError::DuplicateTier { name: tier_name });
     }
-}
+    if tiers.len() >= MAX_TIER_DEFINITIONS {
+        return Err(ContractError::TooManyTiers {
+            max: MAX_TIER_DEFINITIONS,
+        });
+    }
+    tiers.push(tier_name.as_str().into());
 
-pub fn query_tierlist_templates(
-    deps: Deps,
-    start_after: Option<u64>,
-    limit: Option<u32>,
-) -> StdResult<Binary> {
-    let min = start_after.map(Bound::exclusive);
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let tierlists: Vec<_> = TIERLIST_TEMPLATES
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
-        .take(limit as usize)
-        .collect::<Result<Vec<(u64, TierlistTemplate)>, _>>()?;
-    to_binary(&tierlists)
+    template.refresh_content_hash();
+    TIERLIST_TEMPLATES.save(deps.storage, template_id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "add_tier_definition")
+        .add_attribute("template_id", template_id.to_string())
+        .add_attribute("tier_name", tier_name))
 }
 
-pub fn query_tierlists_by_address(
-    deps: Deps,
-    address: String,
-    start_after: Option<u64>,
-    limit: Option<u32>,
-) -> StdResult<Binary> {
-    deps.api.addr_validate(&address).unwrap(); // Validate address
-    let min = start_after.map(Bound::exclusive);
-    let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let tierlists: Vec<_> = TIERLISTS
-        .prefix(address)
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
-        .take(limit as usize)
-        .collect::<Result<Vec<(u64, Tierlist)>, _>>()?;
-    to_binary(&tierlists)
+pub fn execute_remove_tier_definition(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    tier_name: String,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, template_id)?;
+    if !may_edit_template(deps.storage, &template, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator, a collaborator or admin may remove tiers"
+                .to_string(),
+        });
+    }
+
+    let not_found = || ContractError::TierDefinitionNotFound {
+        tier_name: tier_name.clone(),
+    };
+    let tiers = template.tier_definitions.as_mut().ok_or_else(not_found)?;
+    let index = tiers
+        .iter()
+        .position(|t| t.name == tier_name)
+        .ok_or_else(not_found)?;
+    if tiers.len() <= MIN_TIER_DEFINITIONS {
+        return Err(ContractError::TooFewTiers {
+            min: MIN_TIER_DEFINITIONS,
+        });
+    }
+    tiers.remove(index);
+
+    template.refresh_content_hash();
+    TIERLIST_TEMPLATES.save(deps.storage, template_id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "remove_tier_definition")
+        .add_attribute("template_id", template_id.to_string())
+        .add_attribute("tier_name", tier_name))
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::contract::{execute, instantiate, query};
-    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, TemplateResponse, TierlistResponse};
-    use crate::state::{Config, Tierlist, TierlistItem, TierlistTemplate};
-    use cosmwasm_std::from_binary;
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+pub fn execute_reorder_template_items(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+    order: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, id)?;
+    if !may_edit_template(deps.storage, &template, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator, a collaborator or admin may reorder items"
+                .to_string(),
+        });
+    }
 
-    pub const ADDR1: &str = "addr1";
-    pub const ADDR2: &str = "addr2";
+    let mut requested: Vec<_> = order.iter().map(String::as_str).collect();
+    let mut current: Vec<_> = template.items.iter().map(|i| i.name.as_str()).collect();
+    requested.sort_unstable();
+    current.sort_unstable();
+    if requested != current {
+        return Err(ContractError::InvalidReorder {});
+    }
 
-    #[test]
-    fn test_instantiate() {
-        let env = mock_env();
-        let mut deps = mock_dependencies();
-        let info = mock_info(ADDR1, &[]);
-        instantiate(
-            deps.as_mut(),
-            env.clone(),
-            info,
-            InstantiateMsg {
-                admin_address: ADDR1.to_string(),
+    for item in template.items.iter_mut() {
+        // Safe to unwrap, the permutation check guarantees every item is listed
+        let position = order.iter().position(|name| *name == item.name).unwrap();
+        item.order = position as u32;
+    }
+    template.sort_items();
+    template.refresh_content_hash();
+    TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "reorder_template_items")
+        .add_attribute("template_id", id.to_string()))
+}
+
+pub fn execute_set_submissions_open(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+    open: bool,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, id)?;
+    if info.sender != template.creator && !is_owner(deps.storage, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator or admin may open or close submissions".to_string(),
+        });
+    }
+
+    template.submissions_open = open;
+    TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_submissions_open")
+        .add_attribute("id", id.to_string())
+        .add_attribute("open", open.to_string()))
+}
+
+pub fn execute_set_archived(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+    archived: bool,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, id)?;
+    if info.sender != template.creator && !is_owner(deps.storage, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator or admin may archive or restore templates"
+                .to_string(),
+        });
+    }
+
+    template.archived = archived;
+    TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    if archived {
+        ARCHIVED_TEMPLATES.save(deps.storage, id, &Empty {})?;
+    } else {
+        ARCHIVED_TEMPLATES.remove(deps.storage, id);
+    }
+    let action = if archived {
+        "archive_template"
+    } else {
+        "restore_template"
+    };
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("id", id.to_string()))
+}
+
+pub fn execute_set_template_expiry(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+    expires_at: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, id)?;
+    if info.sender != template.creator && !is_owner(deps.storage, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator or admin may set the expiry".to_string(),
+        });
+    }
+
+    template.expires_at = expires_at;
+    TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_template_expiry")
+        .add_attribute("id", id.to_string())
+        .add_attribute(
+            "expires_at",
+            expires_at.map_or("none".to_string(), |h| h.to_string()),
+        ))
+}
+
+pub fn execute_set_template_category(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+    category: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, id)?;
+    if info.sender != template.creator && !is_owner(deps.storage, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator or admin may set the category".to_string(),
+        });
+    }
+    if let Some(category) = &category {
+        if !load_categories(deps.storage)?.contains(category) {
+            return Err(ContractError::InvalidCategory {
+                category: category.clone(),
+            });
+        }
+    }
+
+    if let Some(previous) = &template.category {
+        TEMPLATES_BY_CATEGORY.remove(deps.storage, (previous, id));
+    }
+    if let Some(category) = &category {
+        TEMPLATES_BY_CATEGORY.save(deps.storage, (category, id), &Empty {})?;
+    }
+    template.category = category;
+    TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_template_category")
+        .add_attribute("id", id.to_string())
+        .add_attribute(
+            "category",
+            template.category.unwrap_or_else(|| "none".to_string()),
+        ))
+}
+
+pub fn execute_set_template_slug(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+    slug: Option<String>,
+) -> Result<Response, ContractError> {
+    let template = load_template_or_err(deps.storage, id)?;
+    if !may_edit_template(deps.storage, &template, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator, a collaborator or admin may set the slug"
+                .to_string(),
+        });
+    }
+    if let Some(slug) = &slug {
+        validate_slug(slug)?;
+        if matches!(TEMPLATES_BY_SLUG.may_load(deps.storage, slug)?, Some(other) if other != id) {
+            return Err(ContractError::SlugTaken { slug: slug.clone() });
+        }
+    }
+
+    if let Some(previous) = TEMPLATE_SLUGS.may_load(deps.storage, id)? {
+        TEMPLATES_BY_SLUG.remove(deps.storage, &previous);
+    }
+    match &slug {
+        Some(slug) => {
+            TEMPLATES_BY_SLUG.save(deps.storage, slug, &id)?;
+            TEMPLATE_SLUGS.save(deps.storage, id, slug)?;
+        }
+        None => TEMPLATE_SLUGS.remove(deps.storage, id),
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_template_slug")
+        .add_attribute("id", id.to_string())
+        .add_attribute("slug", slug.unwrap_or_else(|| "none".to_string())))
+}
+
+pub fn execute_update_categories(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let mut categories = load_categories(deps.storage)?;
+    for category in remove {
+        let position = categories
+            .iter()
+            .position(|c| *c == category)
+            .ok_or_else(|| ContractError::InvalidCategory {
+                category: category.clone(),
+            })?;
+        let in_use = TEMPLATES_BY_CATEGORY
+            .prefix(&category)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_some();
+        if in_use {
+            return Err(ContractError::CategoryInUse { category });
+        }
+        categories.remove(position);
+    }
+    for category in add {
+        if !categories.contains(&category) {
+            categories.push(category);
+        }
+    }
+    CATEGORIES.save(deps.storage, &categories)?;
+    Ok(Response::new().add_attribute("action", "update_categories"))
+}
+
+/// Contracts instantiated before categories existed fall back to the defaults
+fn load_categories(storage: &dyn Storage) -> StdResult<Vec<String>> {
+    Ok(CATEGORIES
+        .may_load(storage)?
+        .unwrap_or_else(|| DEFAULT_CATEGORIES.iter().map(|c| c.to_string()).collect()))
+}
+
+pub fn execute_save_tierlist(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tierlist: Tierlist,
+    replace: bool,
+    idempotency_key: Option<String>,
+    force: bool,
+) -> Result<Response, ContractError> {
+    let owner = info.sender.clone();
+    let key = match idempotency_key {
+        Some(key) => key,
+        None => return save_tierlist(deps, env, info, owner, tierlist, replace, force),
+    };
+    if key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+        return Err(ContractError::IdempotencyKeyTooLong {
+            max: MAX_IDEMPOTENCY_KEY_LENGTH,
+        });
+    }
+    let template_id = tierlist.template_id;
+    let last_key = SAVE_IDEMPOTENCY_KEYS.may_load(deps.storage, (&owner, template_id))?;
+    if last_key.as_ref() == Some(&key) {
+        return Ok(refund_funds(Response::new(), &info)
+            .add_attribute("action", "save_tierlist")
+            .add_attribute("template_id", template_id.to_string())
+            .add_attribute("duplicate", "true"));
+    }
+
+    let res = save_tierlist(
+        deps.branch(),
+        env,
+        info,
+        owner.clone(),
+        tierlist,
+        replace,
+        force,
+    )?;
+    SAVE_IDEMPOTENCY_KEYS.save(deps.storage, (&owner, template_id), &key)?;
+    Ok(res.add_attribute("duplicate", "false"))
+}
+
+pub fn execute_save_tierlists(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tierlists: Vec<Tierlist>,
+    best_effort: bool,
+) -> Result<Response, ContractError> {
+    if tierlists.len() > MAX_BATCH_SAVES {
+        return Err(ContractError::TooManyTierlists {
+            max: MAX_BATCH_SAVES,
+        });
+    }
+    // Each save would otherwise forward the same funds again
+    if !info.funds.is_empty() {
+        return Err(ContractError::BatchFundsNotAccepted {});
+    }
+
+    let mut saved = vec![];
+    let mut skipped = vec![];
+    for tierlist in tierlists {
+        let template_id = tierlist.template_id;
+        let owner = info.sender.clone();
+        // Saves only write once every check has passed, so a failed one leaves no trace
+        match save_tierlist(
+            deps.branch(),
+            env.clone(),
+            info.clone(),
+            owner,
+            tierlist,
+            true,
+            false,
+        ) {
+            Ok(_) => saved.push(template_id.to_string()),
+            Err(_) if best_effort => skipped.push(template_id.to_string()),
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "save_tierlists")
+        .add_attribute("saved", saved.join(","))
+        .add_attribute("skipped", skipped.join(",")))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_save_tierlist_for(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    tierlist: Tierlist,
+    signature: Binary,
+    pubkey: Binary,
+    nonce: u64,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let owner = deps.api.addr_validate(&owner)?;
+    if !pubkey_matches_address(&pubkey, owner.as_str()) {
+        return Err(ContractError::PubkeyMismatch {
+            owner: owner.into_string(),
+        });
+    }
+    let expected = NONCES
+        .may_load(deps.storage, owner.as_str())?
+        .unwrap_or_default();
+    if nonce != expected {
+        return Err(ContractError::InvalidNonce {
+            expected,
+            got: nonce,
+        });
+    }
+    let digest = permit_digest(&env, &tierlist, nonce)?;
+    let verified = deps.api.secp256k1_verify(&digest, &signature, &pubkey);
+    if !matches!(verified, Ok(true)) {
+        return Err(ContractError::InvalidSignature {});
+    }
+    NONCES.save(deps.storage, owner.as_str(), &(nonce + 1))?;
+
+    let res = save_tierlist(deps, env, info, owner.clone(), tierlist, true, false)?;
+    Ok(res
+        .add_attribute("action", "save_tierlist_for")
+        .add_attribute("owner", owner)
+        .add_attribute("nonce", nonce.to_string()))
+}
+
+/// Hash the owner signs over, see `SavePermit` for the encoding. Shared by the execute
+/// path and the `SavePermitDigest` query so the two cannot diverge.
+fn permit_digest(env: &Env, tierlist: &Tierlist, nonce: u64) -> StdResult<Binary> {
+    let permit = SavePermit {
+        contract_address: env.contract.address.to_string(),
+        chain_id: env.block.chain_id.clone(),
+        template_id: tierlist.template_id,
+        tierlist_digest: Binary::from(Sha256::digest(&to_binary(tierlist)?).as_slice()),
+        nonce,
+    };
+    Ok(Binary::from(
+        Sha256::digest(&to_binary(&permit)?).as_slice(),
+    ))
+}
+
+/// Cosmos addresses are the bech32 encoded ripemd160 of the sha256 of the compressed pubkey
+fn pubkey_matches_address(pubkey: &[u8], address: &str) -> bool {
+    let data = match bech32::decode(address) {
+        Ok((_, data, _)) => data,
+        Err(_) => return false,
+    };
+    match Vec::<u8>::from_base32(&data) {
+        Ok(data) => data == Ripemd160::digest(&Sha256::digest(pubkey)).as_slice(),
+        Err(_) => false,
+    }
+}
+
+/// Stores the tierlist under `owner`, any fee is paid from `info.funds`
+fn save_tierlist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: Addr,
+    tierlist: Tierlist,
+    replace: bool,
+    force: bool,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &owner) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let id = tierlist.template_id;
+    let template = load_template_or_err(deps.storage, id)?;
+    if template.is_sealed() {
+        return Err(ContractError::TemplateSealed {});
+    }
+    if template.archived {
+        return Err(ContractError::TemplateArchived {});
+    }
+    if !template.submissions_open {
+        return Err(ContractError::SubmissionsClosed {});
+    }
+    if template.is_expired(env.block.height) {
+        return Err(ContractError::TemplateExpired {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let size = to_binary(&tierlist)?.len() as u64;
+    if size > config.max_tierlist_bytes {
+        return Err(ContractError::TierlistTooLarge {
+            size,
+            max: config.max_tierlist_bytes,
+        });
+    }
+    // Templates without their own tiers fall back to the configured defaults
+    let invalid_label = match &template.tier_definitions {
+        Some(_) => tierlist.invalid_tier_label(&template),
+        None => DEFAULT_TIERS
+            .may_load(deps.storage)?
+            .and_then(|tiers| tierlist.invalid_label_among(&tiers)),
+    };
+    if let Some(label) = invalid_label {
+        return Err(ContractError::InvalidTierLabel {
+            label,
+            template_id: id,
+        });
+    }
+    tierlist.validate(&template)?;
+    let max_submissions = template.max_submissions;
+    let creator = template.creator;
+    let fee = TEMPLATE_FEES.may_load(deps.storage, id)?;
+    let payment = fee_payment(&info.funds, fee.as_ref())?;
+
+    // Re-saves never count towards the submission limit
+    let sender = deps.api.addr_validate(owner.as_str())?;
+    let existing = TIERLISTS.may_load(deps.storage, (&sender, id))?;
+    if existing.is_some() && !replace {
+        return Err(ContractError::TierlistAlreadyExists { template_id: id });
+    }
+    if matches!(&existing, Some(existing) if existing.locked) {
+        return Err(ContractError::TierlistLocked {});
+    }
+    // Identical re-saves would only rewrite storage and record a revision, and are not charged
+    if !force
+        && matches!(&existing, Some(existing) if existing.fingerprint() == tierlist.fingerprint())
+    {
+        return Ok(refund_funds(Response::new(), &info).add_attribute("changed", "false"));
+    }
+    if existing.is_none() {
+        let submissions = TEMPLATE_TIERLIST_COUNT
+            .may_load(deps.storage, id)?
+            .unwrap_or_default();
+        if let Some(max) = max_submissions {
+            if submissions >= max as u64 {
+                return Err(ContractError::SubmissionLimitReached { max });
+            }
+        }
+        TEMPLATE_TIERLIST_COUNT.save(deps.storage, id, &(submissions + 1))?;
+    }
+
+    let mut res = Response::new();
+    if let Some(payment) = payment.filter(|payment| !payment.amount.is_zero()) {
+        res = res.add_message(BankMsg::Send {
+            to_address: creator.into_string(),
+            amount: vec![payment],
+        });
+    }
+    res = res
+        .add_attribute("changed", "true")
+        .add_attribute("first_submission", existing.is_none().to_string())
+        .add_attribute(
+            "previous_assigned_count",
+            existing
+                .as_ref()
+                .map_or(0, Tierlist::assigned_count)
+                .to_string(),
+        )
+        .add_attribute("new_assigned_count", tierlist.assigned_count().to_string());
+
+    let revision = write_tierlist(deps.storage, &env.block, &sender, tierlist, existing)?;
+    Ok(res.add_attribute("revision", revision.to_string()))
+}
+
+/// Stores `tierlist` as the owner's next revision and returns it, the replaced record is
+/// kept as a snapshot in the history
+fn write_tierlist(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    owner: &Addr,
+    mut tierlist: Tierlist,
+    existing: Option<Tierlist>,
+) -> StdResult<u64> {
+    let id = tierlist.template_id;
+    let previous = revision_count(storage, owner, id)?;
+    if let Some(existing) = &existing {
+        TIERLIST_HISTORY.save(storage, (owner, id, previous), existing)?;
+        prune_history(storage, owner, id, MAX_HISTORY_SNAPSHOTS as u32)?;
+    }
+
+    // Revisions and locks are tracked by the contract, whatever was submitted is replaced
+    tierlist.revisions = existing.map(|t| t.revisions).unwrap_or_default();
+    tierlist.locked = false;
+    let revision = previous + 1;
+    tierlist.record_revision(revision, block);
+    TIERLIST_REVISION_COUNT.save(storage, (owner, id), &revision)?;
+
+    TIERLISTS.save(storage, (owner, id), &tierlist)?;
+    TIERLISTS_BY_TEMPLATE_INDEX.save(storage, (id, owner), &Empty {})?;
+    Ok(revision)
+}
+
+/// Checks the funds against the template's fee in the style of `cw_utils::must_pay`,
+/// returning the payment to forward. Templates without a fee accept no funds.
+fn fee_payment(funds: &[Coin], fee: Option<&Coin>) -> Result<Option<Coin>, ContractError> {
+    let fee = match fee {
+        Some(fee) => fee,
+        None if funds.is_empty() => return Ok(None),
+        None => return Err(ContractError::FundsNotAccepted {}),
+    };
+    if let Some(other) = funds.iter().find(|c| c.denom != fee.denom) {
+        return Err(ContractError::UnexpectedDenom {
+            denom: other.denom.clone(),
+        });
+    }
+    let provided = funds
+        .iter()
+        .fold(Uint128::zero(), |total, c| total + c.amount);
+    if provided < fee.amount {
+        return Err(ContractError::InsufficientFee {
+            required: fee.amount,
+            provided,
+        });
+    }
+    Ok(Some(coin(provided.u128(), &fee.denom)))
+}
+
+/// Sends the funds of a save that turned out to be a no-op back to the sender
+fn refund_funds(res: Response, info: &MessageInfo) -> Response {
+    if info.funds.is_empty() {
+        return res;
+    }
+    res.add_message(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: info.funds.clone(),
+    })
+}
+
+pub fn execute_save_tierlist_and_comment(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tierlist: Tierlist,
+    comment: String,
+) -> Result<Response, ContractError> {
+    if comment.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(ContractError::CommentTooLong {
+            max: MAX_COMMENT_LENGTH,
+        });
+    }
+
+    let template_id = tierlist.template_id;
+    let res = execute_save_tierlist(
+        deps.branch(),
+        env,
+        info.clone(),
+        tierlist,
+        true,
+        None,
+        false,
+    )?;
+    TIERLIST_COMMENTS.save(deps.storage, (&info.sender, template_id), &comment)?;
+    TIERLISTS_WITH_COMMENTS_INDEX.save(deps.storage, (template_id, &info.sender), &Empty {})?;
+    Ok(res
+        .add_attribute("action", "save_tierlist_and_comment")
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_copy_rankings(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    from_template_id: u64,
+    to_template_id: u64,
+) -> Result<Response, ContractError> {
+    let mut tierlist = load_tierlist_or_err(deps.storage, &info.sender, from_template_id)?;
+    let template = load_template_or_err(deps.storage, to_template_id)?;
+
+    // Syncing against the target keeps shared items' tiers, as long as the target allows them
+    tierlist.sync_with_template(&template);
+    tierlist.template_id = to_template_id;
+    let res = execute_save_tierlist(deps, env, info, tierlist, true, None, false)?;
+    Ok(res
+        .add_attribute("action", "copy_rankings")
+        .add_attribute("from_template_id", from_template_id.to_string())
+        .add_attribute("to_template_id", to_template_id.to_string()))
+}
+
+/// Archived templates can still be synced, it only catches up with their edits
+pub fn execute_sync_tierlist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    let template = load_template_or_err(deps.storage, template_id)?;
+    if template.is_sealed() {
+        return Err(ContractError::TemplateSealed {});
+    }
+    let existing = load_tierlist_or_err(deps.storage, &info.sender, template_id)?;
+    if existing.locked {
+        return Err(ContractError::TierlistLocked {});
+    }
+
+    let mut tierlist = existing.clone();
+    tierlist.sync_with_template(&template);
+    let res = Response::new()
+        .add_attribute("action", "sync_tierlist")
+        .add_attribute("template_id", template_id.to_string());
+    if tierlist.fingerprint() == existing.fingerprint() {
+        return Ok(res.add_attribute("changed", "false"));
+    }
+    let revision = write_tierlist(
+        deps.storage,
+        &env.block,
+        &info.sender,
+        tierlist,
+        Some(existing),
+    )?;
+    Ok(res
+        .add_attribute("changed", "true")
+        .add_attribute("revision", revision.to_string()))
+}
+
+pub fn execute_set_tierlist_locked(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    owner: Option<String>,
+    locked: bool,
+) -> Result<Response, ContractError> {
+    let owner = match owner {
+        Some(owner) => normalize_addr(deps.api, &owner)?,
+        None => info.sender.clone(),
+    };
+    if owner != info.sender && !is_owner(deps.storage, &info.sender)? {
+        return Err(ContractError::Unauthorized {
+            reason: "only the owner or admin may lock tierlists".to_string(),
+        });
+    }
+
+    let template = load_template_or_err(deps.storage, template_id)?;
+    if template.is_sealed() {
+        return Err(ContractError::TemplateSealed {});
+    }
+    let mut tierlist = load_tierlist_or_err(deps.storage, &owner, template_id)?;
+    tierlist.locked = locked;
+    TIERLISTS.save(deps.storage, (&owner, template_id), &tierlist)?;
+    let action = if locked {
+        "lock_tierlist"
+    } else {
+        "unlock_tierlist"
+    };
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("owner", owner)
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_update_ownership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: Action,
+) -> Result<Response, ContractError> {
+    let ownership = update_ownership(deps, &env.block, &info.sender, action)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    require_template_tiers: Option<bool>,
+    hide_creators: Option<bool>,
+    max_tierlist_bytes: Option<u64>,
+    min_items_per_template: Option<u32>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    if let Some(require_template_tiers) = require_template_tiers {
+        config.require_template_tiers = require_template_tiers;
+    }
+    if let Some(hide_creators) = hide_creators {
+        config.hide_creators = hide_creators;
+    }
+    if let Some(max_tierlist_bytes) = max_tierlist_bytes {
+        config.max_tierlist_bytes = max_tierlist_bytes;
+    }
+    if let Some(min_items_per_template) = min_items_per_template {
+        config.min_items_per_template = Some(min_items_per_template).filter(|min| *min > 0);
+    }
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+pub fn execute_set_default_tiers(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tiers: Vec<String>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    if tiers.len() > MAX_DEFAULT_TIERS {
+        return Err(ContractError::InvalidDefaultTiers {
+            reason: format!("at most {} tiers", MAX_DEFAULT_TIERS),
+        });
+    }
+    for (i, tier) in tiers.iter().enumerate() {
+        if tier.is_empty() || tier.chars().count() > MAX_DEFAULT_TIER_LENGTH {
+            return Err(ContractError::InvalidDefaultTiers {
+                reason: format!(
+                    "tier names must be 1 to {} characters",
+                    MAX_DEFAULT_TIER_LENGTH
+                ),
+            });
+        }
+        if tiers[..i].contains(tier) {
+            return Err(ContractError::DuplicateTier { name: tier.clone() });
+        }
+    }
+
+    if tiers.is_empty() {
+        DEFAULT_TIERS.remove(deps.storage);
+    } else {
+        DEFAULT_TIERS.save(deps.storage, &tiers)?;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_default_tiers")
+        .add_attribute("tiers", tiers.join(",")))
+}
+
+pub fn execute_register_external_template(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    external_id: String,
+    metadata_url: String,
+) -> Result<Response, ContractError> {
+    if BANNED_ADDRESSES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AddressBanned {});
+    }
+    if external_id.is_empty() || external_id.len() > MAX_EXTERNAL_ID_LENGTH {
+        return Err(ContractError::InvalidExternalId {
+            max: MAX_EXTERNAL_ID_LENGTH,
+        });
+    }
+    validate_metadata_url(&metadata_url)?;
+    if EXTERNAL_TEMPLATES.has(deps.storage, &external_id) {
+        return Err(ContractError::ExternalTemplateExists { external_id });
+    }
+
+    let template = ExternalTemplateRef {
+        external_id,
+        metadata_url,
+        registered_by: info.sender,
+        created_at: env.block.time,
+    };
+    EXTERNAL_TEMPLATES.save(deps.storage, &template.external_id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "register_external_template")
+        .add_attribute("external_id", template.external_id)
+        .add_attribute("metadata_url", template.metadata_url))
+}
+
+pub fn execute_ban_address(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let address = normalize_addr(deps.api, &address)?;
+    BANNED_ADDRESSES.save(deps.storage, &address, &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "ban_address")
+        .add_attribute("address", address))
+}
+
+pub fn execute_unban_address(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let address = normalize_addr(deps.api, &address)?;
+    BANNED_ADDRESSES.remove(deps.storage, &address);
+    Ok(Response::new()
+        .add_attribute("action", "unban_address")
+        .add_attribute("address", address))
+}
+
+pub fn execute_publish_tierlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    if !TIERLISTS.has(deps.storage, (&info.sender, template_id)) {
+        return Err(ContractError::TierlistNotFound {
+            address: info.sender.into_string(),
+            template_id,
+        });
+    }
+    PUBLIC_TIERLISTS.save(deps.storage, (template_id, &info.sender), &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "publish_tierlist")
+        .add_attribute("address", info.sender)
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_unpublish_tierlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    PUBLIC_TIERLISTS.remove(deps.storage, (template_id, &info.sender));
+    Ok(Response::new()
+        .add_attribute("action", "unpublish_tierlist")
+        .add_attribute("address", info.sender)
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_pin_tierlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let address = normalize_addr(deps.api, &address)?;
+    // Only existing submissions can be pinned
+    if !TIERLISTS.has(deps.storage, (&address, template_id)) {
+        return Err(ContractError::TierlistNotFound {
+            address: address.into_string(),
+            template_id,
+        });
+    }
+    let mut pinned = PINNED_TIERLISTS
+        .may_load(deps.storage, template_id)?
+        .unwrap_or_default();
+    if !pinned.contains(&address) {
+        if pinned.len() >= MAX_PINNED_TIERLISTS {
+            return Err(ContractError::PinLimitReached {
+                max: MAX_PINNED_TIERLISTS,
+            });
+        }
+        pinned.push(address.clone());
+        PINNED_TIERLISTS.save(deps.storage, template_id, &pinned)?;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "pin_tierlist")
+        .add_attribute("address", address)
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_unpin_tierlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let address = normalize_addr(deps.api, &address)?;
+    let mut pinned = PINNED_TIERLISTS
+        .may_load(deps.storage, template_id)?
+        .unwrap_or_default();
+    pinned.retain(|a| *a != address);
+    if pinned.is_empty() {
+        PINNED_TIERLISTS.remove(deps.storage, template_id);
+    } else {
+        PINNED_TIERLISTS.save(deps.storage, template_id, &pinned)?;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "unpin_tierlist")
+        .add_attribute("address", address)
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_seal_template(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let mut template = load_template_or_err(deps.storage, id)?;
+    let privileged = info.sender == template.creator || is_owner(deps.storage, &info.sender)?;
+    if !privileged && !template.is_expired(env.block.height) {
+        return Err(ContractError::Unauthorized {
+            reason: "only the template creator or admin may seal before expiry".to_string(),
+        });
+    }
+    if template.is_sealed() {
+        return Err(ContractError::TemplateSealed {});
+    }
+
+    template.sealed_at = Some((env.block.height, env.block.time));
+    TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "seal_template")
+        .add_attribute("template_id", id.to_string())
+        .add_attribute("sealed_height", env.block.height.to_string()))
+}
+
+pub fn execute_purge_template_tierlists(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    // The template may already be deleted, its orphaned tierlists can still be purged
+    let template = TIERLIST_TEMPLATES.may_load(deps.storage, template_id)?;
+    if matches!(template, Some(t) if t.is_sealed()) {
+        return Err(ContractError::TemplateSealed {});
+    }
+
+    let addresses = TIERLISTS_BY_TEMPLATE_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for address in &addresses {
+        remove_tierlist(deps.storage, address, template_id)?;
+    }
+    let submissions = TEMPLATE_TIERLIST_COUNT
+        .may_load(deps.storage, template_id)?
+        .unwrap_or_default();
+    TEMPLATE_TIERLIST_COUNT.save(
+        deps.storage,
+        template_id,
+        &submissions.saturating_sub(addresses.len() as u64),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "purge_template_tierlists")
+        .add_attribute("template_id", template_id.to_string())
+        .add_attribute("purged", addresses.len().to_string()))
+}
+
+pub fn execute_admin_delete_tierlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    let address = load_moderated_tierlist(deps.as_ref(), &info, &address, template_id)?.0;
+
+    remove_tierlist(deps.storage, &address, template_id)?;
+    let submissions = TEMPLATE_TIERLIST_COUNT
+        .may_load(deps.storage, template_id)?
+        .unwrap_or_default();
+    TEMPLATE_TIERLIST_COUNT.save(deps.storage, template_id, &submissions.saturating_sub(1))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "admin_delete_tierlist")
+        .add_attribute("address", address)
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_admin_redact_tierlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    let (address, mut tierlist) =
+        load_moderated_tierlist(deps.as_ref(), &info, &address, template_id)?;
+
+    tierlist.clear_tiers();
+    TIERLISTS.save(deps.storage, (&address, template_id), &tierlist)?;
+    TIERLIST_COMMENTS.remove(deps.storage, (&address, template_id));
+    TIERLISTS_WITH_COMMENTS_INDEX.remove(deps.storage, (template_id, &address));
+    // Snapshots would otherwise keep serving the redacted labels
+    let snapshots = TIERLIST_HISTORY
+        .prefix((&address, template_id))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (revision, mut snapshot) in snapshots {
+        snapshot.clear_tiers();
+        TIERLIST_HISTORY.save(deps.storage, (&address, template_id, revision), &snapshot)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "admin_redact_tierlist")
+        .add_attribute("address", address)
+        .add_attribute("template_id", template_id.to_string()))
+}
+
+pub fn execute_clear_tierlist_history(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    keep_latest: Option<u32>,
+) -> Result<Response, ContractError> {
+    let deleted = prune_history(
+        deps.storage,
+        &info.sender,
+        template_id,
+        keep_latest.unwrap_or_default(),
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "clear_tierlist_history")
+        .add_attribute("template_id", template_id.to_string())
+        .add_attribute("deleted", deleted.to_string()))
+}
+
+pub fn execute_admin_clear_tierlist_history(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    template_id: u64,
+    keep_latest: Option<u32>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let address = normalize_addr(deps.api, &address)?;
+    let deleted = prune_history(
+        deps.storage,
+        &address,
+        template_id,
+        keep_latest.unwrap_or_default(),
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "admin_clear_tierlist_history")
+        .add_attribute("address", address)
+        .add_attribute("template_id", template_id.to_string())
+        .add_attribute("deleted", deleted.to_string()))
+}
+
+/// Latest revision number of a tierlist, falling back to its recorded revisions for
+/// tierlists saved before `TIERLIST_REVISION_COUNT` was tracked
+fn revision_count(storage: &dyn Storage, address: &Addr, template_id: u64) -> StdResult<u64> {
+    if let Some(count) = TIERLIST_REVISION_COUNT.may_load(storage, (address, template_id))? {
+        return Ok(count);
+    }
+    let tierlist = TIERLISTS.may_load(storage, (address, template_id))?;
+    Ok(tierlist
+        .and_then(|t| t.revisions.last().map(|r| r.revision))
+        .unwrap_or_default())
+}
+
+/// Deletes all but the `keep_latest` most recent snapshots of a tierlist, returning how
+/// many were deleted
+fn prune_history(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    template_id: u64,
+    keep_latest: u32,
+) -> StdResult<u64> {
+    let revisions = TIERLIST_HISTORY
+        .prefix((address, template_id))
+        .keys(storage, None, None, Order::Descending)
+        .skip(keep_latest as usize)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for revision in &revisions {
+        TIERLIST_HISTORY.remove(storage, (address, template_id, *revision));
+    }
+    Ok(revisions.len() as u64)
+}
+
+/// Authorizes an admin moderation action and loads its target, sealed templates are off limits
+fn load_moderated_tierlist(
+    deps: Deps,
+    info: &MessageInfo,
+    address: &str,
+    template_id: u64,
+) -> Result<(Addr, Tierlist), ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let template = TIERLIST_TEMPLATES.may_load(deps.storage, template_id)?;
+    if matches!(template, Some(t) if t.is_sealed()) {
+        return Err(ContractError::TemplateSealed {});
+    }
+
+    let address = normalize_addr(deps.api, address)?;
+    let tierlist = load_tierlist_or_err(deps.storage, &address, template_id)?;
+    Ok((address, tierlist))
+}
+
+/// Removes a tierlist with its comment, snapshots and index entries, counters are left
+/// to the caller
+fn remove_tierlist(storage: &mut dyn Storage, address: &Addr, template_id: u64) -> StdResult<()> {
+    TIERLISTS.remove(storage, (address, template_id));
+    TIERLISTS_BY_TEMPLATE_INDEX.remove(storage, (template_id, address));
+    TIERLIST_COMMENTS.remove(storage, (address, template_id));
+    TIERLISTS_WITH_COMMENTS_INDEX.remove(storage, (template_id, address));
+    PUBLIC_TIERLISTS.remove(storage, (template_id, address));
+    SAVE_IDEMPOTENCY_KEYS.remove(storage, (address, template_id));
+    prune_history(storage, address, template_id, 0)?;
+    if let Some(mut pinned) = PINNED_TIERLISTS.may_load(storage, template_id)? {
+        pinned.retain(|a| a != address);
+        if pinned.is_empty() {
+            PINNED_TIERLISTS.remove(storage, template_id);
+        } else {
+            PINNED_TIERLISTS.save(storage, template_id, &pinned)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::MigrationStatus {} => {
+            to_binary(&MIGRATION_STATUS.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::Config {} => to_binary(&ConfigResponse::new(
+            CONFIG.load(deps.storage)?,
+            get_ownership(deps.storage)?.owner,
+            get_contract_version(deps.storage)?,
+        )),
+        QueryMsg::Ownership {} => to_binary(&get_ownership(deps.storage)?),
+        QueryMsg::DefaultTiers {} => {
+            to_binary(&DEFAULT_TIERS.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::Version {} => to_binary(&get_contract_version(deps.storage)?),
+        QueryMsg::NextTemplateId {} => {
+            to_binary(&NEXT_ID.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::Bootstrap { limit } => query_bootstrap(deps, limit),
+        QueryMsg::Template { id, strict } => query_template(deps, id, strict.unwrap_or(false)),
+        QueryMsg::TemplateBySlug { slug } => {
+            match TEMPLATES_BY_SLUG.may_load(deps.storage, &slug)? {
+                Some(id) => query_template(deps, id, false),
+                None => to_binary(&TemplateResponse { template: None }),
+            }
+        }
+        QueryMsg::TemplateSummary { id } => query_template_summary(deps, id),
+        QueryMsg::TemplateHash { id } => {
+            let template = load_template_or_err(deps.storage, id)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            to_binary(&template.effective_content_hash())
+        }
+        QueryMsg::TemplateFee { template_id } => {
+            let fee = TEMPLATE_FEES.may_load(deps.storage, template_id)?;
+            to_binary(&TemplateFeeResponse {
+                fee_amount: fee.as_ref().map(|fee| fee.amount),
+                fee_token: fee.map(|fee| fee.denom),
+            })
+        }
+        QueryMsg::TemplateWithTierlistCount { id } => query_template_with_tierlist_count(deps, id),
+        QueryMsg::TierlistFromTemplate { id, strict } => {
+            query_tierlist_from_template(deps, id, strict.unwrap_or(false))
+        }
+        QueryMsg::PreviewFromTemplate { id, assignments } => {
+            query_preview_from_template(deps, id, assignments)
+        }
+        QueryMsg::Tierlist {
+            address,
+            id,
+            strict,
+        } => query_tierlist(deps, address, id, strict.unwrap_or(false)),
+        QueryMsg::TierlistWithTemplate {
+            address,
+            template_id,
+        } => query_tierlist_with_template(deps, address, template_id),
+        QueryMsg::TierlistOrdered { address, id } => query_tierlist_ordered(deps, address, id),
+        QueryMsg::HasTierlist {
+            address,
+            template_id,
+        } => {
+            let address = normalize_addr(deps.api, &address)?;
+            to_binary(&HasTierlistResponse {
+                exists: TIERLISTS.has(deps.storage, (&address, template_id)),
+            })
+        }
+        QueryMsg::ParticipantCount { template_id } => to_binary(&ParticipantCountResponse {
+            count: TEMPLATE_TIERLIST_COUNT
+                .may_load(deps.storage, template_id)?
+                .unwrap_or_default(),
+        }),
+        QueryMsg::ExportTemplate { id } => {
+            let config = CONFIG.load(deps.storage)?;
+            let mut template = load_template_or_err(deps.storage, id)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            redact_creator(&config, &mut template.creator);
+            to_binary(&encode_template(&template))
+        }
+        QueryMsg::ExportTierlist {
+            address,
+            template_id,
+        } => {
+            let address = normalize_addr(deps.api, &address)?;
+            let tierlist = load_tierlist_or_err(deps.storage, &address, template_id)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            to_binary(&encode_tierlist(&tierlist))
+        }
+        QueryMsg::Nonce { address } => {
+            let address = normalize_addr(deps.api, &address)?;
+            to_binary(&NonceResponse {
+                nonce: NONCES
+                    .may_load(deps.storage, address.as_str())?
+                    .unwrap_or_default(),
+            })
+        }
+        QueryMsg::SavePermitDigest { owner, tierlist } => {
+            let owner = normalize_addr(deps.api, &owner)?;
+            let nonce = NONCES
+                .may_load(deps.storage, owner.as_str())?
+                .unwrap_or_default();
+            to_binary(&permit_digest(&env, &tierlist, nonce)?)
+        }
+        QueryMsg::TierlistFingerprint { address, id } => {
+            let address = normalize_addr(deps.api, &address)?;
+            let tierlist = load_tierlist_or_err(deps.storage, &address, id)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            to_binary(&TierlistFingerprintResponse {
+                fingerprint: tierlist.fingerprint(),
+            })
+        }
+        QueryMsg::TierlistByTiers {
+            address,
+            template_id,
+        } => query_tierlist_by_tiers(deps, address, template_id),
+        QueryMsg::TierlistRevision {
+            address,
+            template_id,
+            revision,
+        } => query_tierlist_revision(deps, address, template_id, revision),
+        QueryMsg::TierlistHistoryCount {
+            address,
+            template_id,
+        } => {
+            let address = normalize_addr(deps.api, &address)?;
+            to_binary(&revision_count(deps.storage, &address, template_id)?)
+        }
+        QueryMsg::TierlistHistory {
+            address,
+            template_id,
+            start_after_version,
+            limit,
+        } => query_tierlist_history(deps, address, template_id, start_after_version, limit),
+        QueryMsg::TemplatesWithUserTierlist {
+            address,
+            start_after,
+            limit,
+        } => query_templates_with_user_tierlist(deps, address, start_after, limit),
+        QueryMsg::TierlistsByAddress {
+            address,
+            start_after,
+            limit,
+        } => query_tierlists_by_address(deps, address, start_after, limit),
+        QueryMsg::Templates {
+            start_after,
+            limit,
+            open_only,
+            category,
+            language,
+            include_unspecified,
+            sort,
+        } => {
+            let filter = TemplateFilter {
+                open_only: open_only.unwrap_or(false),
+                category,
+                language,
+                include_unspecified: include_unspecified.unwrap_or(false),
+                include_archived: false,
+                include_unlisted: false,
+            };
+            query_tierlist_templates(deps, start_after, limit, filter, sort)
+        }
+        QueryMsg::ArchivedTemplates { start_after, limit } => {
+            query_archived_templates(deps, start_after, limit)
+        }
+        QueryMsg::AnonymousTemplates { start_after, limit } => {
+            query_anonymous_templates(deps, start_after, limit)
+        }
+        QueryMsg::TemplatesCreatedBetween {
+            start,
+            end,
+            start_after,
+            limit,
+        } => query_templates_created_between(deps, start, end, start_after, limit),
+        QueryMsg::TemplatesContainingItem {
+            item_name,
+            start_after,
+            limit,
+        } => query_templates_containing_item(deps, item_name, start_after, limit),
+        QueryMsg::TemplateForks { id } => query_template_forks(deps, id),
+        QueryMsg::CollaboratingTemplates {
+            address,
+            start_after,
+            limit,
+        } => query_collaborating_templates(deps, address, start_after, limit),
+        QueryMsg::ExternalTemplate { external_id } => to_binary(&ExternalTemplateResponse {
+            template: EXTERNAL_TEMPLATES.may_load(deps.storage, &external_id)?,
+        }),
+        QueryMsg::ExternalTemplates { start_after, limit } => {
+            query_external_templates(deps, start_after, limit)
+        }
+        QueryMsg::Categories {} => to_binary(&CategoriesResponse {
+            categories: load_categories(deps.storage)?,
+        }),
+        QueryMsg::SearchTemplates {
+            creator,
+            open_only,
+            language,
+            include_unspecified,
+            include_archived,
+            include_unlisted,
+            start_after,
+            limit,
+        } => {
+            let filter = TemplateFilter {
+                open_only: open_only.unwrap_or(false),
+                category: None,
+                language,
+                include_unspecified: include_unspecified.unwrap_or(false),
+                include_archived: include_archived.unwrap_or(false),
+                include_unlisted: creator.is_some() && include_unlisted.unwrap_or(false),
+            };
+            query_search_templates(deps, creator, filter, start_after, limit)
+        }
+        QueryMsg::TemplatesByItemCount {
+            min_items,
+            max_items,
+            start_after,
+            limit,
+        } => query_templates_by_item_count(deps, min_items, max_items, start_after, limit),
+        QueryMsg::ItemDistribution {
+            template_id,
+            item_name,
+            start_after,
+            limit,
+        } => query_item_distribution(deps, template_id, item_name, start_after, limit),
+        QueryMsg::AllTierlists { start_after, limit } => {
+            query_all_tierlists(deps, start_after, limit)
+        }
+        QueryMsg::TemplateParticipants {
+            template_id,
+            start_after_address,
+            limit,
+        } => query_template_participants(deps, template_id, start_after_address, limit),
+        QueryMsg::TierlistsForTemplate {
+            template_id,
+            start_after_address,
+            limit,
+            include_private,
+        } => query_tierlists_for_template(
+            deps,
+            template_id,
+            start_after_address,
+            limit,
+            include_private,
+        ),
+        QueryMsg::TierlistsWithComments {
+            template_id,
+            start_after_address,
+            limit,
+        } => query_tierlists_with_comments(deps, template_id, start_after_address, limit),
+        QueryMsg::PinnedTierlists { template_id } => query_pinned_tierlists(deps, template_id),
+        QueryMsg::IsBanned { address } => {
+            let address = normalize_addr(deps.api, &address)?;
+            to_binary(&BANNED_ADDRESSES.has(deps.storage, &address))
+        }
+        QueryMsg::BannedAddresses { start_after, limit } => {
+            query_banned_addresses(deps, start_after, limit)
+        }
+        QueryMsg::CompareTierlists {
+            template_id,
+            address_a,
+            address_b,
+        } => query_compare_tierlists(deps, template_id, address_a, address_b),
+        QueryMsg::TierlistAgreementScore {
+            template_id,
+            address_a,
+            address_b,
+        } => query_tierlist_agreement_score(deps, template_id, address_a, address_b),
+        QueryMsg::MostSimilar {
+            template_id,
+            address,
+            start_after,
+            limit,
+        } => query_most_similar(deps, template_id, address, start_after, limit),
+        QueryMsg::AggregateRankings {
+            template_id,
+            start_after,
+            limit,
+            item_start_after,
+            item_limit,
+        } => query_aggregate_rankings(
+            deps,
+            template_id,
+            start_after,
+            limit,
+            item_start_after,
+            item_limit,
+        ),
+        QueryMsg::ControversialItems {
+            template_id,
+            start_after,
+            limit,
+        } => query_controversial_items(deps, template_id, start_after, limit),
+        QueryMsg::WeightedConsensus {
+            template_id,
+            start_after,
+            limit,
+        } => query_weighted_consensus(deps, template_id, start_after, limit),
+    }
+}
+
+pub fn query_template(deps: Deps, id: u64, strict: bool) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let template = TIERLIST_TEMPLATES
+        .may_load(deps.storage, id)?
+        .map(|mut template| {
+            template.sort_items();
+            redact_creator(&config, &mut template.creator);
+            template
+        });
+    if strict && template.is_none() {
+        return Err(StdError::not_found(format!("template {}", id)));
+    }
+    to_binary(&TemplateResponse { template })
+}
+
+pub fn query_template_summary(deps: Deps, id: u64) -> StdResult<Binary> {
+    let template = match TIERLIST_TEMPLATES.may_load(deps.storage, id)? {
+        None => return to_binary(&TemplateSummaryResponse { summary: None }),
+        Some(template) => template,
+    };
+    let config = CONFIG.load(deps.storage)?;
+    let content_hash = template.effective_content_hash();
+    let mut creator = template.creator;
+    redact_creator(&config, &mut creator);
+    let submission_count = TEMPLATE_TIERLIST_COUNT
+        .may_load(deps.storage, id)?
+        .unwrap_or_default();
+    let summary = TemplateSummary {
+        id,
+        title: template.title,
+        creator,
+        item_count: template.items.len() as u64,
+        submission_count,
+        remaining_submissions: template
+            .max_submissions
+            .map(|max| (max as u64).saturating_sub(submission_count)),
+        content_hash,
+    };
+    to_binary(&TemplateSummaryResponse {
+        summary: Some(summary),
+    })
+}
+
+pub fn query_template_with_tierlist_count(deps: Deps, id: u64) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let template = TIERLIST_TEMPLATES
+        .may_load(deps.storage, id)?
+        .map(|mut template| {
+            template.sort_items();
+            redact_creator(&config, &mut template.creator);
+            template
+        });
+    let tierlist_count = TEMPLATE_TIERLIST_COUNT
+        .may_load(deps.storage, id)?
+        .unwrap_or(0);
+    to_binary(&TemplateWithCountResponse {
+        template,
+        tierlist_count,
+    })
+}
+
+pub fn query_tierlist_from_template(deps: Deps, id: u64, strict: bool) -> StdResult<Binary> {
+    // Storage errors are returned as is, only a missing template is lenient
+    let template = TIERLIST_TEMPLATES.may_load(deps.storage, id)?;
+    if strict && template.is_none() {
+        return Err(StdError::not_found(format!("template {}", id)));
+    }
+    match template {
+        None => to_binary(&TierlistResponse { tierlist: None }),
+        Some(template) => to_binary(&TierlistResponse {
+            tierlist: Some(Tierlist::from_template(template)),
+        }),
+    }
+}
+
+pub fn query_preview_from_template(
+    deps: Deps,
+    id: u64,
+    assignments: Vec<(String, String)>,
+) -> StdResult<Binary> {
+    let template = TIERLIST_TEMPLATES
+        .may_load(deps.storage, id)?
+        .ok_or_else(|| StdError::generic_err(ContractError::TemplateNotFound { id }.to_string()))?;
+    let mut tierlist = Tierlist::from_template(template.clone());
+    for (name, tier) in assignments {
+        let mut found = false;
+        for (item, assigned) in tierlist.items_to_tiers.iter_mut() {
+            if item.name == name {
+                *assigned = tier.clone();
+                found = true;
+            }
+        }
+        if !found {
+            return Err(StdError::generic_err(
+                ContractError::ItemNotFound { name }.to_string(),
+            ));
+        }
+    }
+    if let Some(label) = tierlist.invalid_tier_label(&template) {
+        let err = ContractError::InvalidTierLabel {
+            label,
+            template_id: id,
+        };
+        return Err(StdError::generic_err(err.to_string()));
+    }
+    tierlist
+        .validate(&template)
+        .map_err(|err| StdError::generic_err(ContractError::from(err).to_string()))?;
+    to_binary(&tierlist)
+}
+
+pub fn query_tierlist(deps: Deps, address: String, id: u64, strict: bool) -> StdResult<Binary> {
+    let address = normalize_addr(deps.api, &address)?;
+    let tierlist = TIERLISTS.may_load(deps.storage, (&address, id))?;
+    if strict && tierlist.is_none() {
+        return Err(StdError::not_found(format!(
+            "tierlist by {} for template {}",
+            address, id
+        )));
+    }
+    match tierlist {
+        None => to_binary(&TierlistResponse { tierlist: None }),
+        Some(tierlist) => to_binary(&TierlistResponse {
+            tierlist: Some(tierlist),
+        }),
+    }
+}
+
+pub fn query_tierlist_with_template(
+    deps: Deps,
+    address: String,
+    template_id: u64,
+) -> StdResult<Binary> {
+    let address = normalize_addr(deps.api, &address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let template = TIERLIST_TEMPLATES
+        .may_load(deps.storage, template_id)?
+        .map(|mut template| {
+            template.sort_items();
+            redact_creator(&config, &mut template.creator);
+            template
+        });
+    let tierlist = TIERLISTS.may_load(deps.storage, (&address, template_id))?;
+    to_binary(&TierlistWithTemplateResponse { template, tierlist })
+}
+
+pub fn query_tierlist_ordered(deps: Deps, address: String, id: u64) -> StdResult<Binary> {
+    let address = normalize_addr(deps.api, &address)?;
+    let mut tierlist = TIERLISTS.may_load(deps.storage, (&address, id))?;
+    if let Some(tierlist) = tierlist.as_mut() {
+        if let Some(template) = TIERLIST_TEMPLATES.may_load(deps.storage, id)? {
+            tierlist.sort_by_template(&template);
+        }
+    }
+    to_binary(&TierlistResponse { tierlist })
+}
+
+pub fn query_tierlist_revision(
+    deps: Deps,
+    address: String,
+    template_id: u64,
+    revision: u64,
+) -> StdResult<Binary> {
+    let address = normalize_addr(deps.api, &address)?;
+    let tierlist = TIERLIST_HISTORY.may_load(deps.storage, (&address, template_id, revision))?;
+    to_binary(&TierlistResponse { tierlist })
+}
+
+pub fn query_tierlist_history(
+    deps: Deps,
+    address: String,
+    template_id: u64,
+    start_after_version: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let address = normalize_addr(deps.api, &address)?;
+    let min = start_after_version.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let history = TIERLIST_HISTORY
+        .prefix((&address, template_id))
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<(u64, Tierlist)>>>()?;
+    to_binary(&history)
+}
+
+pub fn query_tierlist_templates(
+    deps: Deps,
+    start_after: Option<TemplateCursor>,
+    limit: Option<u32>,
+    filter: TemplateFilter,
+    sort: Option<TemplateSort>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let templates = match (sort.unwrap_or(TemplateSort::Id), start_after) {
+        (TemplateSort::Id, None) => list_templates(deps, &config, None, limit, &filter)?,
+        (TemplateSort::Id, Some(TemplateCursor::Id(id))) => {
+            list_templates(deps, &config, Some(id), limit, &filter)?
+        }
+        (TemplateSort::Newest, None) => list_newest_templates(deps, &config, None, limit, &filter)?,
+        (TemplateSort::Newest, Some(TemplateCursor::Newest(seconds, id))) => {
+            list_newest_templates(deps, &config, Some((seconds, id)), limit, &filter)?
+        }
+        (sort, Some(_)) => {
+            let sort = match sort {
+                TemplateSort::Id => "id",
+                TemplateSort::Newest => "newest",
+            };
+            let err = ContractError::CursorSortMismatch {
+                sort: sort.to_string(),
+            };
+            return Err(StdError::generic_err(err.to_string()));
+        }
+    };
+    to_binary(&templates)
+}
+
+fn list_newest_templates(
+    deps: Deps,
+    config: &Config,
+    start_after: Option<(u64, u64)>,
+    limit: u32,
+    filter: &TemplateFilter,
+) -> StdResult<Vec<(u64, TierlistTemplate)>> {
+    let ids = TEMPLATES_BY_CREATED_AT
+        .keys(
+            deps.storage,
+            None,
+            start_after.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .map(|key| key.map(|(_, id)| id));
+    load_listed_templates(deps, config, ids, limit, filter)
+}
+
+/// Features templates the admin pinned tierlists on first, archived or not, then fills up
+/// with the newest public templates that are not archived
+pub fn query_bootstrap(deps: Deps, limit: Option<u32>) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let pinned = PINNED_TIERLISTS.keys(deps.storage, None, None, Order::Descending);
+    let curated = TemplateFilter {
+        include_archived: true,
+        ..TemplateFilter::default()
+    };
+    let mut templates = load_listed_templates(deps, &config, pinned, limit, &curated)?;
+    let remaining = limit - templates.len() as u32;
+    if remaining > 0 {
+        let newest = TEMPLATES_BY_CREATED_AT
+            .keys(deps.storage, None, None, Order::Descending)
+            .map(|key| key.map(|(_, id)| id))
+            .filter(|id| !matches!(id, Ok(id) if PINNED_TIERLISTS.has(deps.storage, *id)));
+        templates.extend(load_listed_templates(
+            deps,
+            &config,
+            newest,
+            remaining,
+            &TemplateFilter::default(),
+        )?);
+    }
+    to_binary(&BootstrapResponse {
+        config: ConfigResponse::new(
+            config,
+            get_ownership(deps.storage)?.owner,
+            get_contract_version(deps.storage)?,
+        ),
+        templates,
+    })
+}
+
+/// Filters shared by the template listings
+#[derive(Default)]
+pub struct TemplateFilter {
+    pub open_only: bool,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub include_unspecified: bool, // Keeps templates without a language when filtering by one
+    pub include_archived: bool,
+    pub include_unlisted: bool,
+}
+
+impl TemplateFilter {
+    fn matches(&self, template: &TierlistTemplate) -> bool {
+        if self.open_only && !template.submissions_open {
+            return false;
+        }
+        if template.archived && !self.include_archived {
+            return false;
+        }
+        if template.visibility == TemplateVisibility::Unlisted && !self.include_unlisted {
+            return false;
+        }
+        if self.category.is_some() && template.category != self.category {
+            return false;
+        }
+        match (&self.language, &template.language) {
+            (None, _) => true,
+            (Some(_), None) => self.include_unspecified,
+            (Some(wanted), Some(language)) => wanted == language,
+        }
+    }
+}
+
+fn list_templates(
+    deps: Deps,
+    config: &Config,
+    start_after: Option<u64>,
+    limit: u32,
+    filter: &TemplateFilter,
+) -> StdResult<Vec<(u64, TierlistTemplate)>> {
+    let min = start_after.map(Bound::exclusive);
+    let load = |id: StdResult<u64>| {
+        let id = id?;
+        Ok((id, TIERLIST_TEMPLATES.load(deps.storage, id)?))
+    };
+    // Prefer an index so templates outside the filter aren't scanned
+    let templates: Box<dyn Iterator<Item = StdResult<(u64, TierlistTemplate)>>> =
+        match (&filter.category, &filter.language) {
+            (Some(category), _) => Box::new(
+                TEMPLATES_BY_CATEGORY
+                    .prefix(category)
+                    .keys(deps.storage, min, None, Order::Ascending)
+                    .map(load),
+            ),
+            (None, Some(language)) if !filter.include_unspecified => Box::new(
+                TEMPLATES_BY_LANGUAGE
+                    .prefix(language)
+                    .keys(deps.storage, min, None, Order::Ascending)
+                    .map(load),
+            ),
+            _ => TIERLIST_TEMPLATES.range(deps.storage, min, None, Order::Ascending),
+        };
+    templates
+        .filter(|r| match r {
+            Ok((_, template)) => filter.matches(template),
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .try_fold(page_with_capacity(limit), |mut templates, r| {
+            let (id, mut template) = r?;
+            template.sort_items();
+            redact_creator(config, &mut template.creator);
+            templates.push((id, template));
+            Ok(templates)
+        })
+}
+
+pub fn query_tierlists_by_address(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let address = normalize_addr(deps.api, &address)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let tierlists = TIERLISTS
+        .prefix(&address)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .try_fold(page_with_capacity(limit), |mut tierlists, r| {
+            tierlists.push(r?);
+            StdResult::Ok(tierlists)
+        })?;
+    to_binary(&tierlists)
+}
+
+pub fn query_templates_with_user_tierlist(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let address = normalize_addr(deps.api, &address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let entries = TIERLISTS
+        .prefix(&address)
+        .range(deps.storage, min, None, Order::Ascending)
+        .map(|r| {
+            let (template_id, tierlist) = r?;
+            let template = TIERLIST_TEMPLATES.may_load(deps.storage, template_id)?;
+            Ok((template_id, template, tierlist))
+        })
+        .filter(|r: &StdResult<_>| match r {
+            Ok((_, Some(template), _)) => template.visibility == TemplateVisibility::Public,
+            _ => true,
+        })
+        .take(limit as usize)
+        .try_fold(page_with_capacity(limit), |mut entries, r| {
+            let (template_id, template, tierlist) = r?;
+            let template = template.map(|mut template: TierlistTemplate| {
+                template.sort_items();
+                redact_creator(&config, &mut template.creator);
+                template
+            });
+            entries.push(TierlistWithTemplate {
+                template_id,
+                template,
+                tierlist,
+            });
+            StdResult::Ok(entries)
+        })?;
+    to_binary(&entries)
+}
+
+/// Allocates a page up front, bounded so a huge requested limit can't reserve huge memory
+fn page_with_capacity<T>(limit: u32) -> Vec<T> {
+    Vec::with_capacity(limit.min(MAX_LIMIT) as usize)
+}
+
+pub fn query_archived_templates(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let ids = ARCHIVED_TEMPLATES.keys(deps.storage, min, None, Order::Ascending);
+    let filter = TemplateFilter {
+        include_archived: true,
+        ..TemplateFilter::default()
+    };
+    to_binary(&load_listed_templates(deps, &config, ids, limit, &filter)?)
+}
+
+pub fn query_anonymous_templates(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let ids = TEMPLATES_BY_CREATOR.prefix(&Addr::unchecked("")).keys(
+        deps.storage,
+        min,
+        None,
+        Order::Ascending,
+    );
+    let templates = load_listed_templates(deps, &config, ids, limit, &TemplateFilter::default())?;
+    to_binary(&templates)
+}
+
+pub fn query_templates_created_between(
+    deps: Deps,
+    start: Timestamp,
+    end: Timestamp,
+    start_after: Option<(u64, u64)>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    // Start is inclusive and end exclusive, a cursor before the window is ignored
+    let first = (start.seconds(), 0);
+    let end = (end.seconds(), 0);
+    let (min, from) = match start_after {
+        Some(cursor) if cursor >= first => (Bound::exclusive(cursor), cursor),
+        _ => (Bound::inclusive(first), first),
+    };
+    // Storage ranges panic when the lower bound is past the upper one
+    if from >= end {
+        return to_binary(&Vec::<(u64, TierlistTemplate)>::new());
+    }
+    let ids = TEMPLATES_BY_CREATED_AT
+        .keys(
+            deps.storage,
+            Some(min),
+            Some(Bound::exclusive(end)),
+            Order::Ascending,
+        )
+        .map(|key| key.map(|(_, id)| id));
+    to_binary(&load_listed_templates(
+        deps,
+        &config,
+        ids,
+        limit,
+        &TemplateFilter::default(),
+    )?)
+}
+
+pub fn query_templates_containing_item(
+    deps: Deps,
+    item_name: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let ids = TEMPLATES_BY_ITEM.prefix(&item_name).keys(
+        deps.storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    );
+    to_binary(&load_listed_templates(
+        deps,
+        &config,
+        ids,
+        limit,
+        &TemplateFilter::default(),
+    )?)
+}
+
+pub fn query_external_templates(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let min = start_after.as_deref().map(Bound::exclusive);
+    let templates = EXTERNAL_TEMPLATES
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|r| r.map(|(_, template)| template))
+        .collect::<StdResult<Vec<ExternalTemplateRef>>>()?;
+    to_binary(&templates)
+}
+
+/// Templates the address collaborates on, archived ones included since they remain editable
+pub fn query_collaborating_templates(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = normalize_addr(deps.api, &address)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let ids = USER_COLLABORATING.prefix(&address).keys(
+        deps.storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    );
+    let filter = TemplateFilter {
+        include_archived: true,
+        ..TemplateFilter::default()
+    };
+    to_binary(&load_listed_templates(deps, &config, ids, limit, &filter)?)
+}
+
+/// Loads the templates from an index that match the filter for listing
+fn load_listed_templates(
+    deps: Deps,
+    config: &Config,
+    ids: impl Iterator<Item = StdResult<u64>>,
+    limit: u32,
+    filter: &TemplateFilter,
+) -> StdResult<Vec<(u64, TierlistTemplate)>> {
+    ids.map(|id| {
+        let id = id?;
+        Ok((id, TIERLIST_TEMPLATES.load(deps.storage, id)?))
+    })
+    .filter(|r: &StdResult<(u64, TierlistTemplate)>| match r {
+        Ok((_, template)) => filter.matches(template),
+        Err(_) => true,
+    })
+    .take(limit as usize)
+    .try_fold(page_with_capacity(limit), |mut templates, r| {
+        let (id, mut template) = r?;
+        template.sort_items();
+        redact_creator(config, &mut template.creator);
+        templates.push((id, template));
+        Ok(templates)
+    })
+}
+
+pub fn query_template_forks(deps: Deps, id: u64) -> StdResult<Binary> {
+    let mut forks = vec![];
+    for fork in TEMPLATE_FORKS
+        .prefix(id)
+        .keys(deps.storage, None, None, Order::Ascending)
+    {
+        let fork = fork?;
+        if TIERLIST_TEMPLATES.load(deps.storage, fork)?.visibility == TemplateVisibility::Public {
+            forks.push(fork);
+        }
+    }
+    to_binary(&TemplateForksResponse { forks })
+}
+
+pub fn query_templates_by_item_count(
+    deps: Deps,
+    min_items: Option<u32>,
+    max_items: Option<u32>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    if let (Some(min), Some(max)) = (min_items, max_items) {
+        if min > max {
+            let err = ContractError::InvalidItemCountRange { min, max };
+            return Err(StdError::generic_err(err.to_string()));
+        }
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let min_items = min_items.unwrap_or(0) as usize;
+    let max_items = max_items.map_or(usize::MAX, |max| max as usize);
+    let scanned = TIERLIST_TEMPLATES
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<(u64, TierlistTemplate)>>>()?;
+
+    let last_scanned = scanned.last().map(|(id, _)| *id);
+    let templates = scanned
+        .into_iter()
+        .filter(|(_, template)| {
+            TemplateFilter::default().matches(template)
+                && (min_items..=max_items).contains(&template.items.len())
+        })
+        .map(|(id, mut template)| {
+            template.sort_items();
+            redact_creator(&config, &mut template.creator);
+            (id, template)
+        })
+        .collect();
+    to_binary(&TemplatesByItemCountResponse {
+        templates,
+        last_scanned,
+    })
+}
+
+pub fn query_search_templates(
+    deps: Deps,
+    creator: Option<String>,
+    filter: TemplateFilter,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    // Use the creator index when filtering by creator, otherwise scan every template
+    let scanned = match creator {
+        Some(creator) => {
+            let creator = normalize_addr(deps.api, &creator)?;
+            TEMPLATES_BY_CREATOR
+                .prefix(&creator)
+                .keys(deps.storage, min, None, Order::Ascending)
+                .take(limit)
+                .map(|id| {
+                    let id = id?;
+                    Ok((id, TIERLIST_TEMPLATES.load(deps.storage, id)?))
+                })
+                .collect::<StdResult<Vec<(u64, TierlistTemplate)>>>()?
+        }
+        None => TIERLIST_TEMPLATES
+            .range(deps.storage, min, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<(u64, TierlistTemplate)>>>()?,
+    };
+
+    let last_scanned = scanned.last().map(|(id, _)| *id);
+    let config = CONFIG.load(deps.storage)?;
+    let templates = scanned
+        .into_iter()
+        .filter(|(_, template)| filter.matches(template))
+        .map(|(id, mut template)| {
+            template.sort_items();
+            redact_creator(&config, &mut template.creator);
+            (id, template)
+        })
+        .collect();
+    to_binary(&SearchTemplatesResponse {
+        templates,
+        last_scanned,
+    })
+}
+
+pub fn query_item_distribution(
+    deps: Deps,
+    template_id: u64,
+    item_name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let addresses = TIERLISTS_BY_TEMPLATE_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let mut distribution: BTreeMap<String, u64> = BTreeMap::new();
+    for address in &addresses {
+        let tierlist = TIERLISTS.load(deps.storage, (address, template_id))?;
+        let tier = tierlist
+            .items_to_tiers
+            .into_iter()
+            .find(|i| i.0.name == item_name)
+            .map(|i| i.1);
+        match tier {
+            Some(tier) if !tier.is_empty() => *distribution.entry(tier).or_default() += 1,
+            _ => {}
+        }
+    }
+
+    to_binary(&ItemDistributionResponse {
+        distribution: distribution.into_iter().collect(),
+        last_address: addresses.last().cloned(),
+    })
+}
+
+pub fn query_all_tierlists(
+    deps: Deps,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|(address, id)| normalize_addr(deps.api, &address).map(|address| (address, id)))
+        .transpose()?;
+    let min = start_after
+        .as_ref()
+        .map(|(address, id)| Bound::exclusive((address, *id)));
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let tierlists = TIERLISTS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|r| r.map(|((address, id), tierlist)| (address.into_string(), id, tierlist)))
+        .collect::<StdResult<Vec<(String, u64, Tierlist)>>>()?;
+    to_binary(&tierlists)
+}
+
+pub fn query_template_participants(
+    deps: Deps,
+    template_id: u64,
+    start_after_address: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after_address
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let participants = TIERLISTS_BY_TEMPLATE_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|address| address.map(Addr::into_string))
+        .collect::<StdResult<Vec<String>>>()?;
+    to_binary(&participants)
+}
+
+pub fn query_tierlists_for_template(
+    deps: Deps,
+    template_id: u64,
+    start_after_address: Option<String>,
+    limit: Option<u32>,
+    include_private: bool,
+) -> StdResult<Binary> {
+    let start_after = start_after_address
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let index = if include_private {
+        TIERLISTS_BY_TEMPLATE_INDEX
+    } else {
+        PUBLIC_TIERLISTS
+    };
+    let addresses = index
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let tierlists = addresses
+        .into_iter()
+        .map(|address| {
+            let tierlist = TIERLISTS.load(deps.storage, (&address, template_id))?;
+            Ok((address.into_string(), tierlist))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    to_binary(&TierlistsForTemplateResponse { tierlists })
+}
+
+pub fn query_tierlists_with_comments(
+    deps: Deps,
+    template_id: u64,
+    start_after_address: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after_address
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let addresses = TIERLISTS_WITH_COMMENTS_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let tierlists = addresses
+        .into_iter()
+        .map(|address| {
+            let tierlist = TIERLISTS.load(deps.storage, (&address, template_id))?;
+            let comment = TIERLIST_COMMENTS.load(deps.storage, (&address, template_id))?;
+            Ok((address.into_string(), tierlist, comment))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    to_binary(&TierlistsWithCommentsResponse { tierlists })
+}
+
+pub fn query_banned_addresses(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let addresses = BANNED_ADDRESSES
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    to_binary(&BannedAddressesResponse { addresses })
+}
+
+pub fn query_pinned_tierlists(deps: Deps, template_id: u64) -> StdResult<Binary> {
+    let pinned = PINNED_TIERLISTS
+        .may_load(deps.storage, template_id)?
+        .unwrap_or_default();
+    let mut tierlists = vec![];
+    for address in pinned {
+        if let Some(tierlist) = TIERLISTS.may_load(deps.storage, (&address, template_id))? {
+            tierlists.push((address.into_string(), tierlist));
+        }
+    }
+    to_binary(&PinnedTierlistsResponse { tierlists })
+}
+
+pub fn query_tierlist_by_tiers(deps: Deps, address: String, template_id: u64) -> StdResult<Binary> {
+    let tierlist = load_submission(deps, &address, template_id)?;
+    // Deleted templates no longer declare an order, their tiers are grouped alphabetically
+    let tier_definitions = TIERLIST_TEMPLATES
+        .may_load(deps.storage, template_id)?
+        .and_then(|template| template.tier_definitions);
+    to_binary(&TierlistByTiersResponse {
+        tiers: tierlist.group_by_tier(&tier_definitions),
+    })
+}
+
+pub fn query_compare_tierlists(
+    deps: Deps,
+    template_id: u64,
+    address_a: String,
+    address_b: String,
+) -> StdResult<Binary> {
+    let template = TIERLIST_TEMPLATES.load(deps.storage, template_id)?;
+    let a = load_submission(deps, &address_a, template_id)?;
+    let b = load_submission(deps, &address_b, template_id)?;
+
+    let (items, agreement) = compare_tierlists(&a, &b, &template);
+    to_binary(&CompareTierlistsResponse { items, agreement })
+}
+
+pub fn query_tierlist_agreement_score(
+    deps: Deps,
+    template_id: u64,
+    address_a: String,
+    address_b: String,
+) -> StdResult<Binary> {
+    let template = TIERLIST_TEMPLATES.load(deps.storage, template_id)?;
+    let a = load_submission(deps, &address_a, template_id)?;
+    let b = load_submission(deps, &address_b, template_id)?;
+
+    let (items, _) = compare_tierlists(&a, &b, &template);
+    let identical = items.iter().filter(|c| c.agree).count() as u32;
+    let total = items.len() as u32;
+    let score_percentage = match total {
+        0 => 0,
+        total => (identical * 100 / total) as u8,
+    };
+    to_binary(&AgreementScoreResponse {
+        identical_assignments: identical,
+        total_items: total,
+        score_percentage,
+    })
+}
+
+pub fn query_most_similar(
+    deps: Deps,
+    template_id: u64,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let template = TIERLIST_TEMPLATES.load(deps.storage, template_id)?;
+    let own = load_submission(deps, &address, template_id)?;
+    let address = normalize_addr(deps.api, &address)?;
+    let start_after = start_after
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let addresses = TIERLISTS_BY_TEMPLATE_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let mut similar = vec![];
+    for other in &addresses {
+        if *other == address {
+            continue;
+        }
+        let tierlist = TIERLISTS.load(deps.storage, (other, template_id))?;
+        let (_, agreement) = compare_tierlists(&own, &tierlist, &template);
+        similar.push((other.clone(), agreement));
+    }
+    // Stable sort keeps equally similar addresses in address order
+    similar.sort_by_key(|(_, agreement)| std::cmp::Reverse(*agreement));
+    to_binary(&MostSimilarResponse {
+        similar,
+        last_address: addresses.last().cloned(),
+    })
+}
+
+pub fn query_aggregate_rankings(
+    deps: Deps,
+    template_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    item_start_after: Option<String>,
+    item_limit: Option<u32>,
+) -> StdResult<Binary> {
+    let template = TIERLIST_TEMPLATES.load(deps.storage, template_id)?;
+    let start_after = start_after
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let item_limit = item_limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // Only the page's items are counted, keeping the response bounded for large templates
+    let names: BTreeSet<&str> = template
+        .items
+        .iter()
+        .map(|item| item.name.as_str())
+        .collect();
+    let page: Vec<String> = names
+        .into_iter()
+        .filter(|name| !matches!(&item_start_after, Some(after) if *name <= after.as_str()))
+        .take(item_limit)
+        .map(String::from)
+        .collect();
+    let mut counts: BTreeMap<String, BTreeMap<String, u64>> = page
+        .iter()
+        .map(|name| (name.clone(), BTreeMap::new()))
+        .collect();
+
+    let addresses = TIERLISTS_BY_TEMPLATE_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for address in &addresses {
+        let tierlist = TIERLISTS.load(deps.storage, (address, template_id))?;
+        for (item, tier) in tierlist.items_to_tiers {
+            if tier.is_empty() {
+                continue;
+            }
+            if let Some(tiers) = counts.get_mut(&item.name) {
+                *tiers.entry(tier).or_default() += 1;
+            }
+        }
+    }
+
+    let items = counts
+        .into_iter()
+        .map(|(name, tiers)| {
+            let consensus = tiers
+                .iter()
+                .min_by(|(tier_a, count_a), (tier_b, count_b)| {
+                    count_b
+                        .cmp(count_a)
+                        .then_with(|| template.compare_tiers(tier_a, tier_b))
+                })
+                .map(|(tier, _)| tier.clone());
+            AggregatedItem {
+                name,
+                distribution: tiers.into_iter().collect(),
+                consensus,
+            }
+        })
+        .collect();
+    to_binary(&AggregateRankingsResponse {
+        submissions: addresses.len() as u64,
+        items,
+        last_address: addresses.last().cloned(),
+        last_item: page.last().cloned(),
+    })
+}
+
+pub fn query_controversial_items(
+    deps: Deps,
+    template_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let addresses = TIERLISTS_BY_TEMPLATE_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let mut counts: BTreeMap<String, BTreeMap<String, u64>> = BTreeMap::new();
+    for address in &addresses {
+        let tierlist = TIERLISTS.load(deps.storage, (address, template_id))?;
+        for (item, tier) in tierlist.items_to_tiers {
+            if !tier.is_empty() {
+                *counts
+                    .entry(item.name)
+                    .or_default()
+                    .entry(tier)
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    let mut items: Vec<_> = counts
+        .into_iter()
+        .map(|(name, tiers)| {
+            let distribution: Vec<_> = tiers.into_iter().collect();
+            ControversialItem {
+                name,
+                spread: tier_spread(&distribution),
+                distribution,
+            }
+        })
+        .collect();
+    // Stable sort keeps equally divisive items ordered by name
+    items.sort_by_key(|i| std::cmp::Reverse(i.spread));
+    to_binary(&ControversialItemsResponse {
+        items,
+        last_address: addresses.last().cloned(),
+    })
+}
+
+pub fn query_weighted_consensus(
+    deps: Deps,
+    template_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let template = TIERLIST_TEMPLATES.load(deps.storage, template_id)?;
+    if template.tier_definitions.is_none() {
+        return Err(StdError::generic_err(
+            ContractError::TiersRequired {}.to_string(),
+        ));
+    }
+    let start_after = start_after
+        .map(|address| normalize_addr(deps.api, &address))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let addresses = TIERLISTS_BY_TEMPLATE_INDEX
+        .prefix(template_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    // (score total, scored placements) per item
+    let mut scores: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+    for address in &addresses {
+        let tierlist = TIERLISTS.load(deps.storage, (address, template_id))?;
+        for (item, tier) in tierlist.items_to_tiers {
+            if let Some(score) = template.tier_score(&tier) {
+                let (total, placements) = scores.entry(item.name).or_default();
+                *total += score;
+                *placements += 1;
+            }
+        }
+    }
+
+    let mut items: Vec<_> = scores
+        .into_iter()
+        .map(|(name, (total, placements))| WeightedItem {
+            name,
+            average_score: Decimal::from_ratio(total, placements),
+            total_score: total,
+            placements,
+        })
+        .collect();
+    // Stable sort keeps equally scored items ordered by name
+    items.sort_by_key(|i| std::cmp::Reverse(i.average_score));
+    to_binary(&WeightedConsensusResponse {
+        submissions: addresses.len() as u64,
+        items,
+        last_address: addresses.last().cloned(),
+    })
+}
+
+/// Loads an address's submission, erroring with `TierlistNotFound` when there is none
+fn load_submission(deps: Deps, address: &str, template_id: u64) -> StdResult<Tierlist> {
+    let address = normalize_addr(deps.api, address)?;
+    load_tierlist_or_err(deps.storage, &address, template_id).map_err(|err| match err {
+        ContractError::Std(err) => err,
+        err => StdError::generic_err(err.to_string()),
+    })
+}
+
+/// Queries carry no sender, so `hide_creators` blanks creators for every caller
+fn redact_creator(config: &Config, creator: &mut Addr) {
+    if config.hide_creators {
+        *creator = Addr::unchecked("");
+    }
+}
+
+fn validate_items(items: &[TierlistItem], strict_uniqueness: bool) -> Result<(), ContractError> {
+    if items.is_empty() {
+        return Err(ContractError::NoItems {});
+    }
+    if items.len() > MAX_TEMPLATE_ITEMS {
+        return Err(ContractError::TooManyItems {
+            max: MAX_TEMPLATE_ITEMS,
+        });
+    }
+    validate_unique_items(items, strict_uniqueness)
+}
+
+/// Rejects templates with fewer items than the configured minimum
+fn validate_min_items(config: &Config, items: &[TierlistItem]) -> Result<(), ContractError> {
+    match config.min_items_per_template {
+        Some(min) if items.len() < min as usize => Err(ContractError::TooFewItems { min }),
+        _ => Ok(()),
+    }
+}
+
+/// Rejects items sharing a name, or with `strict_uniqueness` items sharing a name and image
+fn validate_unique_items(
+    items: &[TierlistItem],
+    strict_uniqueness: bool,
+) -> Result<(), ContractError> {
+    let mut keys: Vec<_> = items
+        .iter()
+        .map(|i| {
+            (
+                i.name.as_str(),
+                i.image_url.as_deref().filter(|_| strict_uniqueness),
+            )
+        })
+        .collect();
+    keys.sort_unstable();
+    if let Some(w) = keys.windows(2).find(|w| w[0] == w[1]) {
+        return Err(ContractError::DuplicateItem {
+            name: w[0].0.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_alt_names(items: &[TierlistItem]) -> Result<(), ContractError> {
+    for item in items {
+        let alt_names = match &item.alt_names {
+            Some(alt_names) => alt_names,
+            None => continue,
+        };
+        if alt_names.len() > MAX_ALT_NAMES {
+            return Err(ContractError::TooManyAltNames {
+                name: item.name.clone(),
+                max: MAX_ALT_NAMES,
+            });
+        }
+        if alt_names
+            .iter()
+            .any(|(_, name)| name.len() > MAX_ALT_NAME_LENGTH)
+        {
+            return Err(ContractError::AltNameTooLong {
+                name: item.name.clone(),
+                max: MAX_ALT_NAME_LENGTH,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Accepts urls with one of `METADATA_URL_SCHEMES`, something after the scheme and no
+/// whitespace or control characters
+fn validate_metadata_url(url: &str) -> Result<(), ContractError> {
+    let rest = METADATA_URL_SCHEMES
+        .iter()
+        .find_map(|scheme| url.strip_prefix(scheme));
+    let valid = url.len() <= MAX_METADATA_URL_LENGTH
+        && matches!(rest, Some(rest) if !rest.is_empty())
+        && !url.chars().any(|c| c.is_whitespace() || c.is_control());
+    if !valid {
+        return Err(ContractError::InvalidMetadataUrl {
+            url: url.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Accepts lowercase tags such as "en", "ko" or "pt-br"
+fn validate_slug(slug: &str) -> Result<(), ContractError> {
+    let valid = !slug.is_empty()
+        && slug.len() <= MAX_SLUG_LENGTH
+        && slug
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+    if !valid {
+        return Err(ContractError::InvalidSlug {
+            slug: slug.to_string(),
+            max: MAX_SLUG_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+fn validate_language(language: &Option<String>) -> Result<(), ContractError> {
+    let language = match language {
+        Some(language) => language,
+        None => return Ok(()),
+    };
+    let mut subtags = language.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    let valid = (2..=5).contains(&language.len())
+        && (2..=3).contains(&primary.len())
+        && primary.bytes().all(|b| b.is_ascii_lowercase())
+        && subtags.all(|subtag| {
+            !subtag.is_empty()
+                && subtag
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        });
+    if !valid {
+        return Err(ContractError::InvalidLanguage {
+            language: language.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_tier_definitions(
+    config: &Config,
+    tier_definitions: &Option<Vec<TierDef>>,
+) -> Result<(), ContractError> {
+    let declared = matches!(tier_definitions, Some(tiers) if !tiers.is_empty());
+    if config.require_template_tiers && !declared {
+        return Err(ContractError::TiersRequired {});
+    }
+    let tiers = tier_definitions.as_deref().unwrap_or_default();
+    for (i, tier) in tiers.iter().enumerate() {
+        if tiers[..i].iter().any(|t| t.name == tier.name) {
+            return Err(ContractError::DuplicateTier {
+                name: tier.name.clone(),
+            });
+        }
+        if let Some(color) = &tier.color {
+            let hex = color.strip_prefix('#').unwrap_or_default();
+            if color.len() != 7 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(ContractError::InvalidTierColor {
+                    tier: tier.name.clone(),
+                    color: color.clone(),
+                });
+            }
+        }
+        if matches!(&tier.emoji, Some(emoji) if emoji.len() > MAX_TIER_EMOJI_BYTES) {
+            return Err(ContractError::TierEmojiTooLong {
+                tier: tier.name.clone(),
+                max: MAX_TIER_EMOJI_BYTES,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Earlier versions kept the admin in the config
+    if OWNERSHIP.may_load(deps.storage)?.is_none() {
+        let legacy = LEGACY_CONFIG.load(deps.storage)?;
+        initialize_owner(deps.storage, deps.api, Some(&legacy.admin_address))?;
+    }
+    match msg {
+        MigrateMsg::NormalizeAddresses { limit } => migrate_normalize_addresses(deps, limit),
+    }
+}
+
+fn migrate_normalize_addresses(deps: DepsMut, limit: u32) -> Result<Response, ContractError> {
+    let mut status = MIGRATION_STATUS.may_load(deps.storage)?.unwrap_or_default();
+    let mut remaining = limit as usize;
+
+    if !status.templates_done {
+        let start = status.template_cursor.map(Bound::exclusive);
+        let ids = TIERLIST_TEMPLATES
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(remaining)
+            .collect::<StdResult<Vec<u64>>>()?;
+        status.templates_done = ids.len() < remaining;
+        remaining -= ids.len();
+        for id in ids {
+            let mut template = TIERLIST_TEMPLATES.load(deps.storage, id)?;
+            status.template_cursor = Some(id);
+            // Anonymous templates have no creator to normalize
+            if template.is_anonymous() {
+                continue;
+            }
+            let creator = normalize_addr(deps.api, template.creator.as_str())?;
+            // Also indexes templates stored before the creator index existed
+            TEMPLATES_BY_CREATOR.save(deps.storage, (&creator, id), &Empty {})?;
+            if creator == template.creator {
+                continue;
+            }
+            TEMPLATES_BY_CREATOR.remove(deps.storage, (&template.creator, id));
+            template.creator = creator;
+            TIERLIST_TEMPLATES.save(deps.storage, id, &template)?;
+            status.rewritten += 1;
+        }
+    }
+
+    if status.templates_done && !status.tierlists_done {
+        let cursor = status.tierlist_cursor.clone();
+        let start = cursor
+            .as_ref()
+            .map(|(address, id)| Bound::exclusive((address, *id)));
+        let keys = TIERLISTS
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(remaining)
+            .collect::<StdResult<Vec<(Addr, u64)>>>()?;
+        status.tierlists_done = keys.len() < remaining;
+        for (legacy, template_id) in keys {
+            let address = normalize_addr(deps.api, legacy.as_str())?;
+            if address == legacy {
+                // Tierlists stored before the template index existed
+                TIERLISTS_BY_TEMPLATE_INDEX.save(
+                    deps.storage,
+                    (template_id, &legacy),
+                    &Empty {},
+                )?;
+            } else {
+                if TIERLISTS.has(deps.storage, (&address, template_id)) {
+                    status.skipped += 1;
+                } else {
+                    move_tierlist(deps.storage, &legacy, &address, template_id)?;
+                    status.rewritten += 1;
+                }
+            }
+            status.tierlist_cursor = Some((legacy, template_id));
+        }
+    }
+
+    MIGRATION_STATUS.save(deps.storage, &status)?;
+    Ok(Response::new()
+        .add_attribute("action", "normalize_addresses")
+        .add_attribute("rewritten", status.rewritten.to_string())
+        .add_attribute("done", status.tierlists_done.to_string()))
+}
+
+/// Moves a tierlist and everything keyed by its owner from `from` to `to`
+fn move_tierlist(
+    storage: &mut dyn Storage,
+    from: &Addr,
+    to: &Addr,
+    template_id: u64,
+) -> StdResult<()> {
+    let tierlist = TIERLISTS.load(storage, (from, template_id))?;
+    TIERLISTS.save(storage, (to, template_id), &tierlist)?;
+    TIERLISTS_BY_TEMPLATE_INDEX.save(storage, (template_id, to), &Empty {})?;
+    if let Some(comment) = TIERLIST_COMMENTS.may_load(storage, (from, template_id))? {
+        TIERLIST_COMMENTS.save(storage, (to, template_id), &comment)?;
+        TIERLISTS_WITH_COMMENTS_INDEX.save(storage, (template_id, to), &Empty {})?;
+    }
+    if PUBLIC_TIERLISTS.has(storage, (template_id, from)) {
+        PUBLIC_TIERLISTS.save(storage, (template_id, to), &Empty {})?;
+    }
+    let history = TIERLIST_HISTORY
+        .prefix((from, template_id))
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, Tierlist)>>>()?;
+    for (revision, snapshot) in history {
+        TIERLIST_HISTORY.save(storage, (to, template_id, revision), &snapshot)?;
+    }
+    let revision = revision_count(storage, from, template_id)?;
+    TIERLIST_REVISION_COUNT.save(storage, (to, template_id), &revision)?;
+    TIERLIST_REVISION_COUNT.remove(storage, (from, template_id));
+    if let Some(mut pinned) = PINNED_TIERLISTS.may_load(storage, template_id)? {
+        for address in pinned.iter_mut().filter(|address| *address == from) {
+            *address = to.clone();
+        }
+        PINNED_TIERLISTS.save(storage, template_id, &pinned)?;
+    }
+    remove_tierlist(storage, from, template_id)
+}
+
+/// Validates an address and returns it in its normalized (lowercase) form, so that
+/// differently cased inputs map to the same storage key
+fn normalize_addr(api: &dyn Api, address: &str) -> StdResult<Addr> {
+    let normalized = api.addr_humanize(&api.addr_canonicalize(address)?)?;
+    api.addr_validate(normalized.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::{execute, instantiate, migrate, query, CONTRACT_NAME, CONTRACT_VERSION};
+    use crate::error::{ContractError, OwnershipError};
+    use crate::export::{decode_template, decode_tierlist};
+    use crate::msg::{
+        AggregateRankingsResponse, AgreementScoreResponse, BannedAddressesResponse,
+        BootstrapResponse, CategoriesResponse, CompareTierlistsResponse, ConfigResponse,
+        ControversialItemsResponse, ExecuteMsg, ExternalTemplateResponse, HasTierlistResponse,
+        InstantiateMsg, ItemDistributionResponse, MigrateMsg, MostSimilarResponse, NonceResponse,
+        ParticipantCountResponse, PinnedTierlistsResponse, QueryMsg, SavePermit,
+        SearchTemplatesResponse, TemplateCursor, TemplateFeeResponse, TemplateForksResponse,
+        TemplateResponse, TemplateSort, TemplateSummaryResponse, TemplateWithCountResponse,
+        TemplatesByItemCountResponse, TierlistByTiersResponse, TierlistFingerprintResponse,
+        TierlistResponse, TierlistWithTemplate, TierlistWithTemplateResponse,
+        TierlistsForTemplateResponse, TierlistsWithCommentsResponse, WeightedConsensusResponse,
+    };
+    use crate::ownership::{Action, Ownership};
+    use crate::state::{
+        raw, ExternalTemplateRef, MigrationStatus, TemplateVisibility, TierDef, Tierlist,
+        TierlistItem, TierlistTemplate, DEFAULT_MAX_TIERLIST_BYTES, MAX_ALT_NAMES,
+        MAX_ALT_NAME_LENGTH, MAX_METADATA_URL_LENGTH, MAX_SLUG_LENGTH, MAX_TEMPLATE_ITEMS,
+        PINNED_TIERLISTS, TEMPLATES_BY_CREATOR, TEMPLATE_COLLABORATORS, TEMPLATE_TIERLIST_COUNT,
+        TIERLISTS, TIERLIST_COMMENTS, TIERLIST_HISTORY, TIERLIST_TEMPLATES,
+    };
+    use bech32::{ToBase32, Variant};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{
+        attr, coin, from_binary, from_slice, to_binary, Addr, Api, BankMsg, Binary, Decimal, Deps,
+        DepsMut, Env, Response, StdError, Storage, SubMsg, Timestamp, Uint128,
+    };
+    use cw2::ContractVersion;
+    use cw_multi_test::{App, ContractWrapper, Executor};
+    use k256::ecdsa::signature::Signer;
+    use k256::ecdsa::{Signature, SigningKey};
+    use ripemd160::Ripemd160;
+    use sha2::{Digest, Sha256};
+
+    pub const ADDR1: &str = "addr1";
+    pub const ADDR2: &str = "addr2";
+    pub const ADDR3: &str = "addr3";
+
+    /// Templates need at least one item, for tests that don't care which
+    fn named_item(name: &str) -> TierlistItem {
+        TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }
+    }
+
+    #[test]
+    fn test_instantiate() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap();
+        let config: ConfigResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            config,
+            ConfigResponse {
+                admin_address: Some(ADDR1.to_string()),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: DEFAULT_MAX_TIERLIST_BYTES,
+                min_items_per_template: None,
+                contract_name: CONTRACT_NAME.to_string(),
+                contract_version: CONTRACT_VERSION.to_string(),
+            }
+        );
+
+        // Messages and configs from before the flags existed still parse
+        let msg: InstantiateMsg = from_slice(br#"{"admin_address":"addr1"}"#).unwrap();
+        assert!(!msg.require_template_tiers && !msg.hide_creators);
+        deps.storage.set(b"config", br#"{"admin_address":"addr1"}"#);
+        let bin = query(deps.as_ref(), env, QueryMsg::Config {}).unwrap();
+        let config: ConfigResponse = from_binary(&bin).unwrap();
+        assert!(!config.require_template_tiers && !config.hide_creators);
+    }
+
+    #[test]
+    fn test_require_template_tiers() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: true,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let create = |tier_definitions| ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            tier_definitions,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), create(None)).unwrap_err();
+        assert!(matches!(err, ContractError::TiersRequired {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(Some(vec![])),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TiersRequired {}));
+        let msg = create(Some(vec!["S".into()]));
+        execute(deps.as_mut(), env, info.clone(), msg).unwrap();
+
+        // Free-form tiers are allowed when the flag is unset
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        execute(deps.as_mut(), env, info, create(None)).unwrap();
+    }
+
+    #[test]
+    fn test_create_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "C".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let msg = QueryMsg::Template {
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let template: TemplateResponse = from_binary(&bin).unwrap();
+        let mut expected = TierlistTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "C".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: Some(env.block.time),
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+        };
+        expected.refresh_content_hash();
+        assert_eq!(template.template, Some(expected));
+
+        // Every way of creating a template needs items
+        let empty = [
+            ExecuteMsg::CreateTemplate {
+                title: "Empty".to_string(),
+                items: vec![],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            },
+            ExecuteMsg::CreateTemplateWithFee {
+                title: "Empty".to_string(),
+                items: vec![],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                fee_token: "ujuno".to_string(),
+                fee_amount: Uint128::new(100),
+                visibility: None,
+            },
+            ExecuteMsg::CreateTemplateWithCollaborators {
+                title: "Empty".to_string(),
+                items: vec![],
+                collaborators: vec![],
+                visibility: None,
+            },
+            ExecuteMsg::CreateAnonymousTemplate {
+                title: "Empty".to_string(),
+                items: vec![],
+                visibility: None,
+            },
+        ];
+        for msg in empty {
+            let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap_err();
+            assert!(matches!(err, ContractError::NoItems {}));
+        }
+    }
+
+    #[test]
+    fn test_edit_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "C".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "NewTitle".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "C".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            language: None,
+            visibility: None,
+        };
+        // Try and edit as non admin non owner
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // Valid edit
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        // Query the template
+        let msg = QueryMsg::Template {
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let template: TemplateResponse = from_binary(&bin).unwrap();
+        let mut expected = TierlistTemplate {
+            id: 0,
+            title: "NewTitle".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "C".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: Some(env.block.time),
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+        };
+        expected.refresh_content_hash();
+        assert_eq!(template.template, Some(expected));
+
+        // Edits are held to the same item cap as new templates
+        let items = (0..=MAX_TEMPLATE_ITEMS)
+            .map(|i| TierlistItem {
+                name: format!("Item {}", i),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "NewTitle".to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        let err = execute(deps.as_mut(), env, mock_info(ADDR1, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::TooManyItems { max: 100 }));
+    }
+
+    #[test]
+    fn test_delete_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "C".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
+        // Try to delete as non admin or creator
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        // Valid delete
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let msg = QueryMsg::Template {
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: TemplateResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.template, None);
+    }
+
+    #[test]
+    fn test_save_tierlist() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "C".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // Valid tierlist
+        let mut tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![
+                (
+                    TierlistItem {
+                        name: "A".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "S".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "B".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "A".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "C".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "B".to_string(),
+                ),
+            ],
+        };
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: tierlist.clone(),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        tierlist.record_revision(1, &env.block);
+
+        // Query for it
+        let msg = QueryMsg::Tierlist {
+            address: ADDR1.to_string(),
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: TierlistResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.tierlist, Some(tierlist));
+
+        // Query for nonexistent
+        let msg = QueryMsg::Tierlist {
+            address: ADDR2.to_string(),
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: TierlistResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.tierlist, None);
+
+        // Invalid tierlist, missing options
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![
+                (
+                    TierlistItem {
+                        name: "A".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "S".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "B".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "A".to_string(),
+                ),
+            ],
+        };
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+
+        // Invalid tierlist, additional options
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![
+                (
+                    TierlistItem {
+                        name: "A".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "S".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "B".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "A".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "C".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "A".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "D".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "A".to_string(),
+                ),
+            ],
+        };
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap_err();
+    }
+
+    #[test]
+    fn test_save_tierlist_invalid_tier_label() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            tier_definitions: Some(vec!["S".into(), "A".into()]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // Undeclared tier label
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![
+                (
+                    TierlistItem {
+                        name: "A".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "S".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "B".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "F".to_string(),
+                ),
+            ],
+        };
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        match err {
+            ContractError::InvalidTierLabel { label, template_id } => {
+                assert_eq!(label, "F".to_string());
+                assert_eq!(template_id, 0);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+
+        // Declared and unassigned tiers are accepted
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![
+                (
+                    TierlistItem {
+                        name: "A".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "S".to_string(),
+                ),
+                (
+                    TierlistItem {
+                        name: "B".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "".to_string(),
+                ),
+            ],
+        };
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+    }
+
+    #[test]
+    fn test_purge_template_tierlists() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let template = TierlistTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+            tier_definitions: None,
+            max_submissions: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: template.title.clone(),
+            items: template.items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // Two users save tierlists, then the template is deleted
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist::from_template(template.clone()),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::PurgeTemplateTierlists {
+            template_id: 0,
+            limit: 1,
+        };
+        // Only the admin may purge
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+
+        // Purge one at a time until drained
+        for expected in ["1", "1", "0"] {
+            let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+            let purged = res.attributes.iter().find(|a| a.key == "purged").unwrap();
+            assert_eq!(purged.value, expected);
+        }
+
+        for address in [ADDR1, ADDR2] {
+            let msg = QueryMsg::Tierlist {
+                address: address.to_string(),
+                id: 0,
+                strict: None,
+            };
+            let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+            let res: TierlistResponse = from_binary(&bin).unwrap();
+            assert_eq!(res.tierlist, None);
+        }
+    }
+
+    #[test]
+    fn test_max_submissions() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let template = TierlistTemplate {
+            id: 0,
+            title: "Contest".to_string(),
+            items: vec![
+                TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+                TierlistItem {
+                    name: "B".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                },
+            ],
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+            tier_definitions: None,
+            max_submissions: Some(2),
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: template.title.clone(),
+            items: template.items.clone(),
+            tier_definitions: None,
+            max_submissions: Some(2),
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // Fill the cap with distinct addresses
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist::from_template(template.clone()),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::TemplateSummary { id: 0 },
+        )
+        .unwrap();
+        let res: TemplateSummaryResponse = from_binary(&bin).unwrap();
+        let summary = res.summary.unwrap();
+        assert_eq!(summary.submission_count, 2);
+        assert_eq!(summary.remaining_submissions, Some(0));
+
+        // A new address is rejected
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::SubmissionLimitReached { max: 2 }
+        ));
+
+        // An existing submitter can still update
+        let mut tierlist = Tierlist::from_template(template.clone());
+        tierlist.items_to_tiers[0].1 = "S".to_string();
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        // The limit can only be raised once submissions exist
+        let edit = |max_submissions| ExecuteMsg::EditTemplate {
+            id: 0,
+            title: template.title.clone(),
+            items: template.items.clone(),
+            tier_definitions: None,
+            max_submissions,
+            language: None,
+            visibility: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), edit(Some(1))).unwrap_err();
+        assert!(matches!(err, ContractError::SubmissionLimitLowered {}));
+        execute(deps.as_mut(), env.clone(), info, edit(Some(3))).unwrap();
+
+        let bin = query(deps.as_ref(), env, QueryMsg::TemplateSummary { id: 0 }).unwrap();
+        let res: TemplateSummaryResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.summary.unwrap().remaining_submissions, Some(1));
+    }
+
+    #[test]
+    fn test_address_normalization() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let template = TierlistTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+            tier_definitions: None,
+            max_submissions: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: template.title.clone(),
+            items: template.items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let mut tierlist = Tierlist::from_template(template);
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: tierlist.clone(),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        tierlist.record_revision(1, &env.block);
+
+        // Non-canonical casing resolves to the same stored tierlist
+        let msg = QueryMsg::Tierlist {
+            address: ADDR1.to_uppercase(),
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: TierlistResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.tierlist, Some(tierlist.clone()));
+
+        let msg = QueryMsg::TierlistsByAddress {
+            address: ADDR1.to_uppercase(),
+            start_after: None,
+            limit: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: Vec<(u64, Tierlist)> = from_binary(&bin).unwrap();
+        assert_eq!(res, vec![(0, tierlist)]);
+
+        // Invalid addresses error rather than panic
+        let msg = QueryMsg::Tierlist {
+            address: "a".to_string(),
+            id: 0,
+            strict: None,
+        };
+        query(deps.as_ref(), env, msg).unwrap_err();
+    }
+
+    #[test]
+    fn test_set_submissions_open() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let template = TierlistTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            creator: Addr::unchecked(ADDR2),
+            tier_definitions: None,
+            max_submissions: None,
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: template.title.clone(),
+            items: template.items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        // Template 0 is closed, template 1 stays open
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let close = ExecuteMsg::SetSubmissionsOpen { id: 0, open: false };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            close.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), close).unwrap();
+
+        let save = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist::from_template(template),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), save.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::SubmissionsClosed {}));
+
+        // Only the open template is listed when filtering
+        let msg = QueryMsg::Templates {
+            start_after: None,
+            limit: None,
+            open_only: Some(true),
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: Vec<(u64, TierlistTemplate)> = from_binary(&bin).unwrap();
+        assert_eq!(res.iter().map(|t| t.0).collect::<Vec<_>>(), vec![1]);
+
+        let msg = QueryMsg::Templates {
+            start_after: None,
+            limit: None,
+            open_only: None,
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: Vec<(u64, TierlistTemplate)> = from_binary(&bin).unwrap();
+        assert_eq!(res.len(), 2);
+
+        // Reopen as the admin
+        let open = ExecuteMsg::SetSubmissionsOpen { id: 0, open: true };
+        execute(deps.as_mut(), env.clone(), info.clone(), open).unwrap();
+        execute(deps.as_mut(), env, info, save).unwrap();
+    }
+
+    #[test]
+    fn test_set_item_order() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B", "C"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        // A partial reorder leaves the other items sharing the default order
+        let msg = ExecuteMsg::SetItemOrder {
+            template_id: 0,
+            orders: vec![("B".to_string(), 1)],
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let template = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        let orders: Vec<_> = template.items.iter().map(|i| i.order).collect();
+        assert_eq!(orders, vec![0, 1, 0]);
+
+        let orders = vec![
+            ("C".to_string(), 0),
+            ("A".to_string(), 1),
+            ("B".to_string(), 2),
+        ];
+        let msg = ExecuteMsg::SetItemOrder {
+            template_id: 0,
+            orders: orders.clone(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Template {
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        let res: TemplateResponse = from_binary(&bin).unwrap();
+        let template = res.template.unwrap();
+        let names: Vec<_> = template.items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["C", "A", "B"]);
+
+        // Tierlists built before the reorder still validate
+        let mut tierlist = Tierlist::from_template(template.clone());
+        tierlist.items_to_tiers = items.into_iter().map(|i| (i, "".to_string())).collect();
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // Two items may not share an order
+        let msg = ExecuteMsg::SetItemOrder {
+            template_id: 0,
+            orders: vec![("A".to_string(), 2)],
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::DuplicateItemOrder { order: 2 }
+        ));
+        let msg = ExecuteMsg::SetItemOrder {
+            template_id: 0,
+            orders: vec![("A".to_string(), 5), ("B".to_string(), 5)],
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::DuplicateItemOrder { order: 5 }
+        ));
+
+        let msg = ExecuteMsg::SetItemOrder {
+            template_id: 0,
+            orders: vec![("D".to_string(), 3)],
+        };
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::ItemNotFound { .. }));
+    }
+
+    #[test]
+    fn test_query_item_distribution() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        // Item A is ranked S, S and A
+        for (address, tier) in [(ADDR1, "S"), (ADDR2, "S"), (ADDR3, "A")] {
+            let tierlist = Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![
+                    (items[0].clone(), tier.to_string()),
+                    (items[1].clone(), "B".to_string()),
+                ],
+            };
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist,
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(address, &[]), msg).unwrap();
+        }
+
+        let msg = QueryMsg::ItemDistribution {
+            template_id: 0,
+            item_name: "A".to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: ItemDistributionResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            res.distribution,
+            vec![("A".to_string(), 1), ("S".to_string(), 2)]
+        );
+
+        // Paginated by address
+        let msg = QueryMsg::ItemDistribution {
+            template_id: 0,
+            item_name: "A".to_string(),
+            start_after: None,
+            limit: Some(2),
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: ItemDistributionResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.distribution, vec![("S".to_string(), 2)]);
+        assert_eq!(res.last_address, Some(Addr::unchecked(ADDR2)));
+
+        let msg = QueryMsg::ItemDistribution {
+            template_id: 0,
+            item_name: "A".to_string(),
+            start_after: Some(ADDR2.to_string()),
+            limit: Some(2),
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: ItemDistributionResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.distribution, vec![("A".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_search_templates() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        // 0, 1 and 3 by ADDR1 with 1 closed, 2 by ADDR2
+        for creator in [ADDR1, ADDR1, ADDR2, ADDR1] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(creator, &[]),
+                msg.clone(),
+            )
+            .unwrap();
+        }
+        let msg = ExecuteMsg::SetSubmissionsOpen { id: 1, open: false };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let search = |creator: Option<&str>, start_after, limit| {
+            let msg = QueryMsg::SearchTemplates {
+                creator: creator.map(|c| c.to_string()),
+                open_only: Some(true),
+                start_after,
+                limit,
+                language: None,
+                include_unspecified: None,
+                include_archived: None,
+                include_unlisted: None,
+            };
+            let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+            let res: SearchTemplatesResponse = from_binary(&bin).unwrap();
+            let ids: Vec<u64> = res.templates.iter().map(|t| t.0).collect();
+            (ids, res.last_scanned)
+        };
+
+        assert_eq!(search(Some(ADDR1), None, None), (vec![0, 3], Some(3)));
+        assert_eq!(search(None, None, None), (vec![0, 2, 3], Some(3)));
+
+        // Pages may be short, the cursor is the last scanned id
+        assert_eq!(search(Some(ADDR1), None, Some(2)), (vec![0], Some(1)));
+        assert_eq!(search(Some(ADDR1), Some(1), Some(2)), (vec![3], Some(3)));
+        assert_eq!(search(Some(ADDR1), Some(3), Some(2)), (vec![], None));
+    }
+
+    #[test]
+    fn test_query_tierlist_with_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let mut template = TierlistTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            creator: Addr::unchecked(ADDR1),
+            tier_definitions: None,
+            max_submissions: None,
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: Some(env.block.time),
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+        };
+        template.refresh_content_hash();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: template.title.clone(),
+            items: template.items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let mut tierlist = Tierlist::from_template(template.clone());
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: tierlist.clone(),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        tierlist.record_revision(1, &env.block);
+
+        let query_both = |deps: Deps, address: &str| {
+            let msg = QueryMsg::TierlistWithTemplate {
+                address: address.to_string(),
+                template_id: 0,
+            };
+            let bin = query(deps, env.clone(), msg).unwrap();
+            from_binary::<TierlistWithTemplateResponse>(&bin).unwrap()
+        };
+
+        // Both present
+        let res = query_both(deps.as_ref(), ADDR1);
+        assert_eq!(res.template, Some(template.clone()));
+        assert_eq!(res.tierlist, Some(tierlist.clone()));
+
+        // Template without a tierlist
+        let res = query_both(deps.as_ref(), ADDR2);
+        assert_eq!(res.template, Some(template));
+        assert_eq!(res.tierlist, None);
+
+        // Tierlist for a deleted template, and neither
+        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let res = query_both(deps.as_ref(), ADDR1);
+        assert_eq!(res.template, None);
+        assert_eq!(res.tierlist, Some(tierlist));
+        let res = query_both(deps.as_ref(), ADDR2);
+        assert_eq!(res.template, None);
+        assert_eq!(res.tierlist, None);
+    }
+
+    #[test]
+    fn test_query_template_hash() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::TemplateHash { id: 0 }).unwrap();
+        let hash: Binary = from_binary(&bin).unwrap();
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::TemplateSummary { id: 0 },
+        )
+        .unwrap();
+        let res: TemplateSummaryResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.summary.unwrap().content_hash, hash);
+
+        // Editing the content changes the hash
+        let msg = ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "Tierlist 2".to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::TemplateHash { id: 0 }).unwrap();
+        let edited: Binary = from_binary(&bin).unwrap();
+        assert_ne!(edited, hash);
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Template {
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        let res: TemplateResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.template.unwrap().content_hash, edited);
+
+        // Templates stored before hashes were tracked are hashed on read
+        let mut legacy = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        legacy.content_hash = Binary::default();
+        TIERLIST_TEMPLATES
+            .save(&mut deps.storage, 0, &legacy)
+            .unwrap();
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::TemplateHash { id: 0 }).unwrap();
+        assert_eq!(from_binary::<Binary>(&bin).unwrap(), edited);
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::TemplateSummary { id: 0 },
+        )
+        .unwrap();
+        let res: TemplateSummaryResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.summary.unwrap().content_hash, edited);
+
+        let err = query(deps.as_ref(), env, QueryMsg::TemplateHash { id: 1 }).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Template id 1 has never been assigned")
+        );
+    }
+
+    #[test]
+    fn test_sync_tierlist() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B", "C"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items[..2].to_vec(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![
+                (items[0].clone(), "S".to_string()),
+                (items[1].clone(), "A".to_string()),
+            ],
+        };
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // The template gains C and drops A
+        let msg = ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            items: items[1..].to_vec(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // Nothing to sync for a user without a tierlist
+        let msg = ExecuteMsg::SyncTierlist { template_id: 0 };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+        assert!(res.attributes.contains(&attr("revision", "2")));
+
+        let query_msg = QueryMsg::Tierlist {
+            address: ADDR1.to_string(),
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+        let res: TierlistResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            res.tierlist.unwrap().items_to_tiers,
+            vec![
+                (items[1].clone(), "A".to_string()),
+                (items[2].clone(), "".to_string()),
+            ]
+        );
+        // The pre-sync tierlist is kept in the history like any replaced save
+        let snapshot = TIERLIST_HISTORY
+            .load(&deps.storage, (&Addr::unchecked(ADDR1), 0, 1))
+            .unwrap();
+        assert_eq!(
+            snapshot.items_to_tiers[0],
+            (items[0].clone(), "S".to_string())
+        );
+
+        // Syncing an up to date tierlist records nothing
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+        assert!(res.attributes.contains(&attr("changed", "false")));
+        assert!(!TIERLIST_HISTORY.has(&deps.storage, (&Addr::unchecked(ADDR1), 0, 2)));
+
+        // Locked tierlists and banned owners cannot sync
+        let lock = ExecuteMsg::LockTierlist {
+            template_id: 0,
+            owner: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), lock).unwrap();
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TierlistLocked {}));
+        let unlock = ExecuteMsg::UnlockTierlist {
+            template_id: 0,
+            owner: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), unlock).unwrap();
+        let ban = ExecuteMsg::BanAddress {
+            address: ADDR1.to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), ban).unwrap();
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::AddressBanned {}));
+    }
+
+    #[test]
+    fn test_query_template_with_tierlist_count() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let query_count = |deps: Deps| {
+            let msg = QueryMsg::TemplateWithTierlistCount { id: 0 };
+            let bin = query(deps, env.clone(), msg).unwrap();
+            let res: TemplateWithCountResponse = from_binary(&bin).unwrap();
+            assert!(res.template.is_some());
+            res.tierlist_count
+        };
+        assert_eq!(query_count(deps.as_ref()), 0);
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Template {
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        let res: TemplateResponse = from_binary(&bin).unwrap();
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist::from_template(res.template.unwrap()),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        assert_eq!(query_count(deps.as_ref()), 1);
+
+        // Re-saves are not counted twice
+        for address in [ADDR1, ADDR2, ADDR3] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(address, &[]),
+                msg.clone(),
+            )
+            .unwrap();
+        }
+        assert_eq!(query_count(deps.as_ref()), 3);
+
+        let msg = QueryMsg::TemplateWithTierlistCount { id: 1 };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: TemplateWithCountResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.template, None);
+        assert_eq!(res.tierlist_count, 0);
+    }
+
+    #[test]
+    fn test_tierlist_revisions() {
+        let mut env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let save = |tier: &str| ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(items[0].clone(), tier.to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let query_tierlist = |deps: Deps| {
+            let msg = QueryMsg::Tierlist {
+                address: ADDR1.to_string(),
+                id: 0,
+                strict: None,
+            };
+            let bin = query(deps, mock_env(), msg).unwrap();
+            from_binary::<TierlistResponse>(&bin)
+                .unwrap()
+                .tierlist
+                .unwrap()
+        };
+        let query_revision = |deps: Deps, revision| {
+            let msg = QueryMsg::TierlistRevision {
+                address: ADDR1.to_string(),
+                template_id: 0,
+                revision,
+            };
+            let bin = query(deps, mock_env(), msg).unwrap();
+            from_binary::<TierlistResponse>(&bin).unwrap().tierlist
+        };
+
+        // Each save is recorded with its block
+        for tier in ["S", "A", "B"] {
+            env.block.height += 1;
+            env.block.time = env.block.time.plus_seconds(5);
+            execute(deps.as_mut(), env.clone(), info.clone(), save(tier)).unwrap();
+        }
+        let tierlist = query_tierlist(deps.as_ref());
+        let revisions: Vec<_> = tierlist.revisions.iter().map(|r| r.revision).collect();
+        assert_eq!(revisions, vec![1, 2, 3]);
+        assert!(tierlist
+            .revisions
+            .windows(2)
+            .all(|w| w[0].height < w[1].height && w[0].time < w[1].time));
+        let snapshot = query_revision(deps.as_ref(), 2).unwrap();
+        assert_eq!(snapshot.items_to_tiers[0].1, "A".to_string());
+
+        // Only the latest revisions are kept
+        for i in 0..22 {
+            env.block.height += 1;
+            let tier = if i % 2 == 0 { "S" } else { "A" };
+            execute(deps.as_mut(), env.clone(), info.clone(), save(tier)).unwrap();
+        }
+        let tierlist = query_tierlist(deps.as_ref());
+        assert_eq!(tierlist.revisions.len(), 20);
+        assert_eq!(tierlist.revisions[0].revision, 6);
+        assert_eq!(tierlist.revisions[19].revision, 25);
+        assert_eq!(query_revision(deps.as_ref(), 19), None);
+        assert_eq!(
+            query_revision(deps.as_ref(), 20).unwrap().revisions.len(),
+            20
+        );
+        // Snapshots hold replaced records, the current revision has none yet
+        let snapshot = query_revision(deps.as_ref(), 24).unwrap();
+        assert_eq!(snapshot.revisions.last().unwrap().revision, 24);
+        assert_eq!(snapshot.items_to_tiers[0].1, "S".to_string());
+        assert_eq!(query_revision(deps.as_ref(), 25), None);
+    }
+
+    #[test]
+    fn test_save_tierlist_and_comment() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![(items[0].clone(), "S".to_string())],
+        };
+
+        // Comments are capped at 280 characters
+        let msg = ExecuteMsg::SaveTierlistAndComment {
+            tierlist: tierlist.clone(),
+            comment: "a".repeat(281),
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert!(matches!(err, ContractError::CommentTooLong { max: 280 }));
+
+        // An invalid tierlist saves neither
+        let msg = ExecuteMsg::SaveTierlistAndComment {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![],
+            },
+            comment: "Great list".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert!(!TIERLIST_COMMENTS.has(&deps.storage, (&Addr::unchecked(ADDR1), 0)));
+
+        let msg = ExecuteMsg::SaveTierlistAndComment {
+            tierlist,
+            comment: "Great list".to_string(),
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "action" && a.value == "save_tierlist_and_comment"));
+        let comment = TIERLIST_COMMENTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR1), 0))
+            .unwrap();
+        assert_eq!(comment, "Great list".to_string());
+
+        let msg = QueryMsg::Tierlist {
+            address: ADDR1.to_string(),
+            id: 0,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: TierlistResponse = from_binary(&bin).unwrap();
+        assert!(res.tierlist.is_some());
+    }
+
+    #[test]
+    fn test_reorder_template_items() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B", "C"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: i as u32,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let reorder = |names: &[&str]| ExecuteMsg::ReorderTemplateItems {
+            id: 0,
+            order: names.iter().map(|n| n.to_string()).collect(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            reorder(&["C", "A", "B"]),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // Missing, duplicated and unknown names are all rejected
+        for names in [
+            vec!["C", "A"],
+            vec!["C", "A", "A"],
+            vec!["C", "A", "D"],
+            vec!["C", "A", "B", "B"],
+        ] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(ADDR2, &[]),
+                reorder(&names),
+            )
+            .unwrap_err();
+            assert!(matches!(err, ContractError::InvalidReorder {}));
+        }
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            reorder(&["C", "A", "B"]),
+        )
+        .unwrap();
+        let template = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        let stored: Vec<_> = template
+            .items
+            .iter()
+            .map(|i| (i.name.as_str(), i.order))
+            .collect();
+        assert_eq!(stored, vec![("C", 0), ("A", 1), ("B", 2)]);
+    }
+
+    #[test]
+    fn test_seal_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![(items[0].clone(), "S".to_string())],
+        };
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: tierlist.clone(),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::SealTemplate { id: 0 };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        // Sealing is irreversible and recorded on the template
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateSealed {}));
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Template {
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        let res: TemplateResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            res.template.unwrap().sealed_at,
+            Some((env.block.height, env.block.time))
+        );
+
+        // No writes to the template's tierlists are accepted, even from the admin
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateSealed {}));
+        let msg = ExecuteMsg::SyncTierlist { template_id: 0 };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateSealed {}));
+        let msg = ExecuteMsg::LockTierlist {
+            template_id: 0,
+            owner: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateSealed {}));
+        let msg = ExecuteMsg::PurgeTemplateTierlists {
+            template_id: 0,
+            limit: 10,
+        };
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateSealed {}));
+    }
+
+    #[test]
+    fn test_query_tierlists_with_comments() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![(items[0].clone(), "S".to_string())],
+        };
+
+        // ADDR2 saves without a comment, ADDR1 and ADDR3 comment
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: tierlist.clone(),
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        for (addr, comment) in [(ADDR1, "First"), (ADDR3, "Third")] {
+            let msg = ExecuteMsg::SaveTierlistAndComment {
+                tierlist: tierlist.clone(),
+                comment: comment.to_string(),
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let query_page = |deps: Deps, start_after_address: Option<&str>, limit| {
+            let msg = QueryMsg::TierlistsWithComments {
+                template_id: 0,
+                start_after_address: start_after_address.map(String::from),
+                limit,
+            };
+            let bin = query(deps, mock_env(), msg).unwrap();
+            let res: TierlistsWithCommentsResponse = from_binary(&bin).unwrap();
+            res.tierlists
+                .into_iter()
+                .map(|(address, _, comment)| (address, comment))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            query_page(deps.as_ref(), None, None),
+            vec![
+                (ADDR1.to_string(), "First".to_string()),
+                (ADDR3.to_string(), "Third".to_string())
+            ]
+        );
+        assert_eq!(
+            query_page(deps.as_ref(), Some(ADDR1), Some(1)),
+            vec![(ADDR3.to_string(), "Third".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_hide_creators() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: true,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Template {
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        let res: TemplateResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.template.unwrap().creator, Addr::unchecked(""));
+
+        let msg = QueryMsg::Templates {
+            start_after: None,
+            limit: None,
+            open_only: None,
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: Vec<(u64, TierlistTemplate)> = from_binary(&bin).unwrap();
+        assert_eq!(res[0].1.creator, Addr::unchecked(""));
+
+        let bin = query(deps.as_ref(), env, QueryMsg::TemplateSummary { id: 0 }).unwrap();
+        let res: TemplateSummaryResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.summary.unwrap().creator, Addr::unchecked(""));
+
+        // Storage keeps the real creator so authorization still works
+        let template = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        assert_eq!(template.creator, Addr::unchecked(ADDR2));
+    }
+
+    #[test]
+    fn test_query_controversial_items() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B", "C"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        // A is always S, B is split between S and F, C splits two to one
+        for (addr, tiers) in [
+            (ADDR1, ["S", "S", "S"]),
+            (ADDR2, ["S", "F", "S"]),
+            (ADDR3, ["S", "S", "F"]),
+            ("addr4", ["S", "F", "S"]),
+        ] {
+            let tierlist = Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: items
+                    .iter()
+                    .cloned()
+                    .zip(tiers.iter().map(|t| t.to_string()))
+                    .collect(),
+            };
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist,
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let msg = QueryMsg::ControversialItems {
+            template_id: 0,
+            start_after: None,
+            limit: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: ControversialItemsResponse = from_binary(&bin).unwrap();
+        let spreads: Vec<_> = res
+            .items
+            .iter()
+            .map(|i| (i.name.as_str(), i.spread))
+            .collect();
+        assert_eq!(
+            spreads,
+            vec![
+                ("B", Decimal::percent(50)),
+                ("C", Decimal::percent(25)),
+                ("A", Decimal::zero())
+            ]
+        );
+        assert_eq!(
+            res.items[0].distribution,
+            vec![("F".to_string(), 2), ("S".to_string(), 2)]
+        );
+
+        assert_eq!(res.last_address, Some(Addr::unchecked("addr4")));
+
+        // Pages cover disjoint submissions
+        let page = |start_after: Option<String>| {
+            let msg = QueryMsg::ControversialItems {
+                template_id: 0,
+                start_after,
+                limit: Some(2),
+            };
+            let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+            from_binary::<ControversialItemsResponse>(&bin).unwrap()
+        };
+        let first = page(None);
+        assert_eq!(first.last_address, Some(Addr::unchecked(ADDR2)));
+        assert_eq!(first.items[0].name, "B");
+        let second = page(first.last_address.map(String::from));
+        assert_eq!(second.last_address, Some(Addr::unchecked("addr4")));
+        assert_eq!(
+            second.items[0].distribution,
+            vec![("F".to_string(), 1), ("S".to_string(), 1)]
+        );
+        assert_eq!(page(second.last_address.map(String::from)).items, vec![]);
+    }
+
+    #[test]
+    fn test_pin_tierlist() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let addrs: Vec<_> = (0..6).map(|i| format!("addr{}", i)).collect();
+        for addr in &addrs {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(named_item("A"), "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let pin = |address: &str| ExecuteMsg::PinTierlist {
+            address: address.to_string(),
+            template_id: 0,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            pin(&addrs[0]),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        // Only saved tierlists can be pinned
+        execute(deps.as_mut(), env.clone(), info.clone(), pin("addr9")).unwrap_err();
+
+        for addr in &addrs[..5] {
+            execute(deps.as_mut(), env.clone(), info.clone(), pin(addr)).unwrap();
+        }
+        // Re-pinning is a no-op, a sixth pin is over the cap
+        execute(deps.as_mut(), env.clone(), info.clone(), pin(&addrs[0])).unwrap();
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), pin(&addrs[5])).unwrap_err();
+        assert!(matches!(err, ContractError::PinLimitReached { max: 5 }));
+
+        let msg = ExecuteMsg::UnpinTierlist {
+            address: addrs[0].clone(),
+            template_id: 0,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        execute(deps.as_mut(), env.clone(), info.clone(), pin(&addrs[5])).unwrap();
+
+        let msg = QueryMsg::PinnedTierlists { template_id: 0 };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: PinnedTierlistsResponse = from_binary(&bin).unwrap();
+        let pinned: Vec<_> = res.tierlists.into_iter().map(|(a, _)| a).collect();
+        assert_eq!(pinned, addrs[1..].to_vec());
+
+        // Removed tierlists are unpinned, whichever way they were removed
+        let msg = ExecuteMsg::PurgeTemplateTierlists {
+            template_id: 0,
+            limit: 2,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let msg = ExecuteMsg::AdminDeleteTierlist {
+            address: addrs[5].clone(),
+            template_id: 0,
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+        let pinned = PINNED_TIERLISTS.load(&deps.storage, 0).unwrap();
+        let expected: Vec<_> = addrs[2..5].iter().map(Addr::unchecked).collect();
+        assert_eq!(pinned, expected);
+    }
+
+    #[test]
+    fn test_query_bootstrap() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        for i in 0..35 {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: format!("Tierlist {}", i),
+                items: vec![named_item("A")],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+
+        let msg = QueryMsg::Bootstrap { limit: Some(2) };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: BootstrapResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            res.config,
+            ConfigResponse {
+                admin_address: Some(ADDR1.to_string()),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: DEFAULT_MAX_TIERLIST_BYTES,
+                min_items_per_template: None,
+                contract_name: CONTRACT_NAME.to_string(),
+                contract_version: CONTRACT_VERSION.to_string(),
+            }
+        );
+        let ids = |res: &BootstrapResponse| -> Vec<u64> {
+            res.templates.iter().map(|(id, _)| *id).collect()
+        };
+        assert_eq!(ids(&res), vec![34, 33]);
+
+        // Pinned templates lead even when archived, other archived templates are left out
+        for id in [1, 33] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: id,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(named_item("A"), "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+            let msg = ExecuteMsg::PinTierlist {
+                address: ADDR2.to_string(),
+                template_id: id,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        for id in [1, 34] {
+            let msg = ExecuteMsg::ArchiveTemplate { id };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        let msg = QueryMsg::Bootstrap { limit: Some(4) };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: BootstrapResponse = from_binary(&bin).unwrap();
+        assert_eq!(ids(&res), vec![33, 1, 32, 31]);
+
+        // Large limits are capped
+        let msg = QueryMsg::Bootstrap { limit: Some(100) };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: BootstrapResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.templates.len(), 30);
+    }
+
+    #[test]
+    fn test_query_compare_tierlists() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: Some(vec!["S".into(), "A".into()]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        for (addr, tiers) in [(ADDR1, ["S", "S"]), (ADDR2, ["S", "A"])] {
+            let tierlist = Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: items
+                    .iter()
+                    .cloned()
+                    .zip(tiers.iter().map(|t| t.to_string()))
+                    .collect(),
+            };
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist,
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let compare = |deps: Deps, address_b: &str| {
+            let msg = QueryMsg::CompareTierlists {
+                template_id: 0,
+                address_a: ADDR1.to_string(),
+                address_b: address_b.to_string(),
+            };
+            query(deps, mock_env(), msg)
+        };
+        let bin = compare(deps.as_ref(), ADDR2).unwrap();
+        let res: CompareTierlistsResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.agreement, Decimal::percent(50));
+        assert_eq!(res.items[1].distance, Some(1));
+
+        // ADDR3 never submitted
+        let err = compare(deps.as_ref(), ADDR3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Generic error: addr3 has not submitted a tierlist for template 0"
+        );
+    }
+
+    #[test]
+    fn test_ban_address() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let create = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        let save = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(named_item("A"), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), create.clone()).unwrap();
+
+        let ban = |address: &str| ExecuteMsg::BanAddress {
+            address: address.to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            ban(ADDR3),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        execute(deps.as_mut(), env.clone(), info.clone(), ban(ADDR2)).unwrap();
+        execute(deps.as_mut(), env.clone(), info.clone(), ban(ADDR3)).unwrap();
+
+        for msg in [create.clone(), save.clone()] {
+            let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+            assert!(matches!(err, ContractError::AddressBanned {}));
+        }
+
+        let is_banned = |deps: Deps, address: &str| -> bool {
+            let msg = QueryMsg::IsBanned {
+                address: address.to_string(),
+            };
+            from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap()
+        };
+        assert!(is_banned(deps.as_ref(), ADDR2));
+        assert!(!is_banned(deps.as_ref(), ADDR1));
+        let msg = QueryMsg::BannedAddresses {
+            start_after: None,
+            limit: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: BannedAddressesResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            res.addresses,
+            vec![Addr::unchecked(ADDR2), Addr::unchecked(ADDR3)]
+        );
+
+        let msg = ExecuteMsg::UnbanAddress {
+            address: ADDR2.to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        assert!(!is_banned(deps.as_ref(), ADDR2));
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), create).unwrap();
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), save).unwrap();
+    }
+
+    #[test]
+    fn test_save_tierlist_unknown_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 999,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(named_item("A"), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTemplateId { id: 999 }));
+    }
+
+    #[test]
+    fn test_query_most_similar() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B", "C", "D"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        for (addr, tiers) in [
+            (ADDR1, ["S", "S", "A", "B"]),
+            (ADDR2, ["S", "A", "B", "C"]),
+            (ADDR3, ["S", "S", "A", "B"]),
+            ("addr4", ["S", "S", "B", "C"]),
+            ("addr5", ["F", "F", "F", "F"]),
+        ] {
+            let tierlist = Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: items
+                    .iter()
+                    .cloned()
+                    .zip(tiers.iter().map(|t| t.to_string()))
+                    .collect(),
+            };
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist,
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let most_similar = |deps: Deps, start_after: Option<&str>, limit| {
+            let msg = QueryMsg::MostSimilar {
+                template_id: 0,
+                address: ADDR1.to_string(),
+                start_after: start_after.map(String::from),
+                limit,
+            };
+            let bin = query(deps, mock_env(), msg).unwrap();
+            from_binary::<MostSimilarResponse>(&bin).unwrap()
+        };
+        let res = most_similar(deps.as_ref(), None, None);
+        assert_eq!(
+            res.similar,
+            vec![
+                (Addr::unchecked(ADDR3), Decimal::one()),
+                (Addr::unchecked("addr4"), Decimal::percent(50)),
+                (Addr::unchecked(ADDR2), Decimal::percent(25)),
+                (Addr::unchecked("addr5"), Decimal::zero()),
+            ]
+        );
+
+        // Pages scan submissions in address order
+        let res = most_similar(deps.as_ref(), None, Some(2));
+        assert_eq!(
+            res.similar,
+            vec![(Addr::unchecked(ADDR2), Decimal::percent(25))]
+        );
+        assert_eq!(res.last_address, Some(Addr::unchecked(ADDR2)));
+        let res = most_similar(deps.as_ref(), Some(ADDR2), Some(2));
+        assert_eq!(
+            res.similar,
+            vec![
+                (Addr::unchecked(ADDR3), Decimal::one()),
+                (Addr::unchecked("addr4"), Decimal::percent(50)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_query_template_participants() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        for addr in [ADDR3, ADDR1, ADDR2] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(named_item("A"), "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let participants = |deps: Deps, start_after_address: Option<&str>, limit| {
+            let msg = QueryMsg::TemplateParticipants {
+                template_id: 0,
+                start_after_address: start_after_address.map(String::from),
+                limit,
+            };
+            from_binary::<Vec<String>>(&query(deps, mock_env(), msg).unwrap()).unwrap()
+        };
+        assert_eq!(
+            participants(deps.as_ref(), None, None),
+            vec![ADDR1.to_string(), ADDR2.to_string(), ADDR3.to_string()]
+        );
+        assert_eq!(
+            participants(deps.as_ref(), Some(ADDR1), Some(1)),
+            vec![ADDR2.to_string()]
+        );
+        assert_eq!(
+            participants(deps.as_ref(), Some(ADDR2), None),
+            vec![ADDR3.to_string()]
+        );
+    }
+
+    #[test]
+    fn test_query_all_tierlists() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: vec![named_item("A")],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        for (addr, template_id) in [(ADDR2, 1), (ADDR1, 0), (ADDR2, 0)] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(named_item("A"), "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let all_tierlists = |deps: Deps, start_after: Option<(&str, u64)>, limit| {
+            let msg = QueryMsg::AllTierlists {
+                start_after: start_after.map(|(a, id)| (a.to_string(), id)),
+                limit,
+            };
+            let bin = query(deps, mock_env(), msg).unwrap();
+            from_binary::<Vec<(String, u64, Tierlist)>>(&bin)
+                .unwrap()
+                .into_iter()
+                .map(|(address, id, _)| (address, id))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            all_tierlists(deps.as_ref(), None, Some(2)),
+            vec![(ADDR1.to_string(), 0), (ADDR2.to_string(), 0)]
+        );
+        assert_eq!(
+            all_tierlists(deps.as_ref(), Some((ADDR2, 0)), Some(2)),
+            vec![(ADDR2.to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_raw_query() {
+        let mut app = App::default();
+        let code_id = app.store_code(Box::new(ContractWrapper::new(execute, instantiate, query)));
+        let contract = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(ADDR1),
+                &InstantiateMsg {
+                    admin_address: ADDR1.to_string(),
+                    require_template_tiers: false,
+                    hide_creators: false,
+                    max_tierlist_bytes: None,
+                },
+                &[],
+                "tierlist",
+                None,
+            )
+            .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        app.execute_contract(Addr::unchecked(ADDR2), contract.clone(), &msg, &[])
+            .unwrap();
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(items[0].clone(), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        app.execute_contract(Addr::unchecked(ADDR3), contract.clone(), &msg, &[])
+            .unwrap();
+
+        let bin = app
+            .wrap()
+            .query_wasm_raw(contract.clone(), raw::template_key(0))
+            .unwrap()
+            .unwrap();
+        let template: TierlistTemplate = from_slice(&bin).unwrap();
+        assert_eq!(template.title, "Tierlist 1".to_string());
+        assert_eq!(template.creator, Addr::unchecked(ADDR2));
+
+        let key = raw::tierlist_key(&Addr::unchecked(ADDR3), 0);
+        let bin = app.wrap().query_wasm_raw(contract, key).unwrap().unwrap();
+        let tierlist: Tierlist = from_slice(&bin).unwrap();
+        assert_eq!(tierlist.get_tier(items[0].clone()), "S".to_string());
+    }
+
+    #[test]
+    fn test_create_template_with_fee() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let create = |fee_token: &str, fee_amount| ExecuteMsg::CreateTemplateWithFee {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            fee_token: fee_token.to_string(),
+            fee_amount: Uint128::new(fee_amount),
+            language: None,
+            visibility: None,
+        };
+        for msg in [create("ujuno", 0), create("", 100)] {
+            let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+            assert!(matches!(err, ContractError::InvalidTemplateFee {}));
+        }
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            create("ujuno", 100),
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "template_id" && a.value == "0"));
+        let save = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(named_item("A"), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[coin(50, "ujuno")]),
+            save.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientFee { required, provided }
+                if required == Uint128::new(100) && provided == Uint128::new(50)
+        ));
+        // Other denoms are rejected rather than kept by the contract
+        let funds = [coin(100, "ujuno"), coin(500, "uatom")];
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &funds),
+            save.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnexpectedDenom { denom } if denom == "uatom"));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[coin(100, "ujuno")]),
+            save.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: ADDR2.to_string(),
+                amount: vec![coin(100, "ujuno")],
+            })]
+        );
+
+        // An identical re-save is not charged, the payment goes back to the sender
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info(ADDR3, &[coin(100, "ujuno")]),
+            save,
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: ADDR3.to_string(),
+                amount: vec![coin(100, "ujuno")],
+            })]
+        );
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "changed" && a.value == "false"));
+    }
+
+    #[test]
+    fn test_admin_moderation() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        for addr in [ADDR2, ADDR3] {
+            let msg = ExecuteMsg::SaveTierlistAndComment {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(items[0].clone(), "Offensive".to_string())],
+                },
+                comment: "Offensive".to_string(),
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(items[0].clone(), "Still offensive".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), msg).unwrap();
+
+        let delete = ExecuteMsg::AdminDeleteTierlist {
+            address: ADDR2.to_string(),
+            template_id: 0,
+        };
+        let redact = ExecuteMsg::AdminRedactTierlist {
+            address: ADDR3.to_string(),
+            template_id: 0,
+        };
+        for msg in [delete.clone(), redact.clone()] {
+            let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+            assert!(matches!(
+                err,
+                ContractError::Ownership(OwnershipError::NotOwner {})
+            ));
+        }
+
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), delete.clone()).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "address" && a.value == ADDR2));
+        let addr2 = Addr::unchecked(ADDR2);
+        assert!(!TIERLISTS.has(&deps.storage, (&addr2, 0)));
+        assert!(!TIERLIST_COMMENTS.has(&deps.storage, (&addr2, 0)));
+        assert_eq!(TEMPLATE_TIERLIST_COUNT.load(&deps.storage, 0).unwrap(), 1);
+        // Deleting twice does not decrement the counter again
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), delete).unwrap_err();
+        assert!(matches!(err, ContractError::TierlistNotFound { .. }));
+        assert_eq!(TEMPLATE_TIERLIST_COUNT.load(&deps.storage, 0).unwrap(), 1);
+
+        execute(deps.as_mut(), env.clone(), info, redact).unwrap();
+        let addr3 = Addr::unchecked(ADDR3);
+        let tierlist = TIERLISTS.load(&deps.storage, (&addr3, 0)).unwrap();
+        assert_eq!(
+            tierlist.items_to_tiers,
+            vec![(items[0].clone(), "".to_string())]
+        );
+        assert!(!TIERLIST_COMMENTS.has(&deps.storage, (&addr3, 0)));
+        let snapshot = TIERLIST_HISTORY
+            .load(&deps.storage, (&addr3, 0, 1))
+            .unwrap();
+        assert_eq!(snapshot.items_to_tiers, tierlist.items_to_tiers);
+        assert_eq!(TEMPLATE_TIERLIST_COUNT.load(&deps.storage, 0).unwrap(), 1);
+
+        let msg = QueryMsg::TierlistsWithComments {
+            template_id: 0,
+            start_after_address: None,
+            limit: None,
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: TierlistsWithCommentsResponse = from_binary(&bin).unwrap();
+        assert!(res.tierlists.is_empty());
+    }
+
+    #[test]
+    fn test_template_expiry() {
+        let mut env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let expires_at = env.block.height + 10;
+        let msg = ExecuteMsg::SetTemplateExpiry {
+            id: 0,
+            expires_at: Some(expires_at),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let save = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(named_item("A"), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let seal = ExecuteMsg::SealTemplate { id: 0 };
+        // Saves are accepted up to and including the expiry height
+        env.block.height = expires_at;
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            save.clone(),
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            seal.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        env.block.height = expires_at + 1;
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), save).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateExpired {}));
+
+        // Expired templates are still queryable and anyone may seal them
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Template {
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        let res: TemplateResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.template.unwrap().expires_at, Some(expires_at));
+        execute(deps.as_mut(), env, mock_info(ADDR3, &[]), seal).unwrap();
+    }
+
+    #[test]
+    fn test_template_fee() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplateWithFee {
+            title: "Paid".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            fee_token: "ujuno".to_string(),
+            fee_amount: Uint128::new(100),
+            language: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Free".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let fee = |deps: Deps, template_id| {
+            let msg = QueryMsg::TemplateFee { template_id };
+            from_binary::<TemplateFeeResponse>(&query(deps, mock_env(), msg).unwrap()).unwrap()
+        };
+        let free = TemplateFeeResponse {
+            fee_token: None,
+            fee_amount: None,
+        };
+        assert_eq!(
+            fee(deps.as_ref(), 0),
+            TemplateFeeResponse {
+                fee_token: Some("ujuno".to_string()),
+                fee_amount: Some(Uint128::new(100)),
+            }
+        );
+        assert_eq!(fee(deps.as_ref(), 1), free);
+
+        let msg = ExecuteMsg::RemoveTemplateFee { template_id: 0 };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        assert_eq!(fee(deps.as_ref(), 0), free);
+
+        // Saves are free again
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(named_item("A"), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[coin(100, "ujuno")]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::FundsNotAccepted {}));
+        let res = execute(deps.as_mut(), env, mock_info(ADDR3, &[]), msg).unwrap();
+        assert!(res.messages.is_empty());
+    }
+
+    #[test]
+    fn test_copy_rankings() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let make_items = |names: &[&str]| -> Vec<TierlistItem> {
+            names
+                .iter()
+                .map(|name| TierlistItem {
+                    name: name.to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                })
+                .collect()
+        };
+        for names in [["A", "B", "C"], ["B", "A", "D"]] {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: make_items(&names),
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+
+        let copy = ExecuteMsg::CopyRankings {
+            from_template_id: 0,
+            to_template_id: 1,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            copy.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TierlistNotFound { .. }));
+
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: make_items(&["A", "B", "C"])
+                    .into_iter()
+                    .zip(["S", "A", "B"].iter().map(|t| t.to_string()))
+                    .collect(),
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), copy).unwrap();
+
+        let msg = QueryMsg::Tierlist {
+            address: ADDR2.to_string(),
+            id: 1,
+            strict: None,
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: TierlistResponse = from_binary(&bin).unwrap();
+        let tierlist = res.tierlist.unwrap();
+        let tiers: Vec<_> = tierlist
+            .items_to_tiers
+            .iter()
+            .map(|(item, tier)| (item.name.as_str(), tier.as_str()))
+            .collect();
+        assert_eq!(tiers, vec![("B", "A"), ("A", "S"), ("D", "")]);
+        assert_eq!(tierlist.revisions.len(), 1);
+    }
+
+    #[test]
+    fn test_list_queries_large_dataset() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let count = 150u64;
+        for id in 0..count {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: format!("Tierlist {}", id),
+                items: vec![named_item("A")],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: id,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(named_item("A"), "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+
+        // Page through both listings with an odd page size, huge limits must not over-allocate
+        let mut template_ids = vec![];
+        let mut tierlist_ids = vec![];
+        let mut start_after = None;
+        loop {
+            let msg = QueryMsg::Templates {
+                start_after: start_after.map(TemplateCursor::Id),
+                limit: Some(7),
+                open_only: None,
+                category: None,
+                language: None,
+                include_unspecified: None,
+                sort: None,
+            };
+            let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+            let page: Vec<(u64, TierlistTemplate)> = from_binary(&bin).unwrap();
+            let msg = QueryMsg::TierlistsByAddress {
+                address: ADDR1.to_string(),
+                start_after,
+                limit: Some(7),
+            };
+            let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+            let tierlists: Vec<(u64, Tierlist)> = from_binary(&bin).unwrap();
+            if page.is_empty() {
+                assert!(tierlists.is_empty());
+                break;
+            }
+            start_after = page.last().map(|(id, _)| *id);
+            template_ids.extend(page.into_iter().map(|(id, _)| id));
+            tierlist_ids.extend(tierlists.into_iter().map(|(id, _)| id));
+        }
+        assert_eq!(template_ids, (0..count).collect::<Vec<_>>());
+        assert_eq!(tierlist_ids, template_ids);
+
+        let msg = QueryMsg::TierlistsByAddress {
+            address: ADDR1.to_string(),
+            start_after: None,
+            limit: Some(u32::MAX),
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let tierlists: Vec<(u64, Tierlist)> = from_binary(&bin).unwrap();
+        assert_eq!(tierlists.len() as u64, count);
+
+        // Listings that load templates are capped
+        let msg = QueryMsg::Templates {
+            start_after: None,
+            limit: Some(u32::MAX),
+            open_only: None,
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let page: Vec<(u64, TierlistTemplate)> = from_binary(&bin).unwrap();
+        assert_eq!(page.len(), 30);
+        let msg = QueryMsg::TemplatesWithUserTierlist {
+            address: ADDR1.to_string(),
+            start_after: None,
+            limit: Some(u32::MAX),
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let entries: Vec<TierlistWithTemplate> = from_binary(&bin).unwrap();
+        assert_eq!(entries.len(), 30);
+    }
+
+    #[test]
+    fn test_patch_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let make_items = |names: &[&str]| -> Vec<TierlistItem> {
+            names
+                .iter()
+                .map(|name| TierlistItem {
+                    name: name.to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                })
+                .collect()
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: make_items(&["A", "B"]),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let patch =
+            |title: Option<&str>, items: Option<Vec<TierlistItem>>| ExecuteMsg::PatchTemplate {
+                id: 0,
+                title: title.map(String::from),
+                items,
+            };
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            patch(Some("Stolen"), None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // Only the title changes
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            patch(Some("Tierlist 2"), None),
+        )
+        .unwrap();
+        let template = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        assert_eq!(template.title, "Tierlist 2".to_string());
+        assert_eq!(template.items, make_items(&["A", "B"]));
+
+        // Items are validated
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            patch(None, Some(vec![])),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoItems {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            patch(None, Some(make_items(&["A", "C", "A"]))),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateItem { name } if name == "A"));
+
+        // Only the items change
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            patch(None, Some(make_items(&["C"]))),
+        )
+        .unwrap();
+        let patched = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        assert_eq!(patched.title, "Tierlist 2".to_string());
+        assert_eq!(patched.items, make_items(&["C"]));
+        assert_ne!(patched.content_hash, template.content_hash);
+
+        let res = execute(deps.as_mut(), env, mock_info(ADDR2, &[]), patch(None, None)).unwrap();
+        assert!(res.attributes.iter().any(|a| a.key == "warning"));
+        assert_eq!(TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap(), patched);
+    }
+
+    #[test]
+    fn test_specific_not_found_errors() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::DeleteTemplate { id: 4 },
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Template id 4 has never been assigned");
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let msg = ExecuteMsg::SyncTierlist { template_id: 0 };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "addr1 has not submitted a tierlist for template 0"
+        );
+
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(
+                    TierlistItem {
+                        name: "Z".to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    },
+                    "S".to_string(),
+                )],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid tierlist, item 'Z' is not part of the template"
+        );
+
+        // Deleted templates are told apart from ids that were never assigned
+        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateNotFound { id: 0 }));
+        assert_eq!(err.to_string(), "Template 0 does not exist");
+    }
+
+    #[test]
+    fn test_query_version() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let bin = query(deps.as_ref(), env, QueryMsg::Version {}).unwrap();
+        let version: ContractVersion = from_binary(&bin).unwrap();
+        assert_eq!(version.contract, CONTRACT_NAME.to_string());
+        assert_eq!(version.version, CONTRACT_VERSION.to_string());
+    }
+
+    #[test]
+    fn test_query_templates_with_user_tierlist() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        for id in 0..3 {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: format!("Tierlist {}", id),
+                items: vec![named_item("A")],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        for template_id in [0, 2] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(named_item("A"), "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        }
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::DeleteTemplate { id: 0 },
+        )
+        .unwrap();
+
+        let msg = QueryMsg::TemplatesWithUserTierlist {
+            address: ADDR2.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
+        let res: Vec<TierlistWithTemplate> = from_binary(&bin).unwrap();
+        let entries: Vec<_> = res
+            .iter()
+            .map(|e| (e.template_id, e.template.as_ref().map(|t| t.title.as_str())))
+            .collect();
+        // The deleted template is kept as a tombstone
+        assert_eq!(entries, vec![(0, None), (2, Some("Tierlist 2"))]);
+        assert_eq!(res[1].tierlist.template_id, 2);
+
+        let msg = QueryMsg::TemplatesWithUserTierlist {
+            address: ADDR2.to_string(),
+            start_after: Some(0),
+            limit: Some(1),
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: Vec<TierlistWithTemplate> = from_binary(&bin).unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].template_id, 2);
+    }
+
+    #[test]
+    fn test_max_tierlist_bytes() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: Some(512),
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let save = |tier: String| ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(items[0].clone(), tier)],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+
+        // Free-form tier labels are the one unbounded part of a tierlist
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            save("S".repeat(1000)),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TierlistTooLarge { max: 512, .. }
+        ));
+        execute(deps.as_mut(), env, info, save("S".to_string())).unwrap();
+    }
+
+    #[test]
+    fn test_publish_tierlist() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        // Only saved tierlists can be published
+        let publish = ExecuteMsg::PublishTierlist { template_id: 0 };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), publish.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TierlistNotFound { .. }));
+
+        for addr in [ADDR1, ADDR2] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(named_item("A"), "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let listed = |deps: Deps, include_private: bool| {
+            let msg = QueryMsg::TierlistsForTemplate {
+                template_id: 0,
+                start_after_address: None,
+                limit: None,
+                include_private,
+            };
+            let res: TierlistsForTemplateResponse =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.tierlists
+                .into_iter()
+                .map(|(address, _)| address)
+                .collect::<Vec<_>>()
+        };
+        // Tierlists are private until published
+        assert!(listed(deps.as_ref(), false).is_empty());
+        assert_eq!(listed(deps.as_ref(), true), vec![ADDR1, ADDR2]);
+
+        execute(deps.as_mut(), env.clone(), info.clone(), publish).unwrap();
+        assert_eq!(listed(deps.as_ref(), false), vec![ADDR1]);
+
+        let msg = ExecuteMsg::UnpublishTierlist { template_id: 0 };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+        assert!(listed(deps.as_ref(), false).is_empty());
+        assert_eq!(listed(deps.as_ref(), true), vec![ADDR1, ADDR2]);
+    }
+
+    #[test]
+    fn test_preview_from_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![
+            TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            },
+            TierlistItem {
+                name: "B".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            },
+        ];
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: Some(vec!["S".into(), "A".into()]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+
+        let preview = |deps: Deps, assignments: Vec<(&str, &str)>| {
+            let msg = QueryMsg::PreviewFromTemplate {
+                id: 0,
+                assignments: assignments
+                    .into_iter()
+                    .map(|(name, tier)| (name.to_string(), tier.to_string()))
+                    .collect(),
+            };
+            query(deps, mock_env(), msg)
+        };
+        let tierlist: Tierlist =
+            from_binary(&preview(deps.as_ref(), vec![("B", "S")]).unwrap()).unwrap();
+        assert_eq!(tierlist.template_id, 0);
+        assert_eq!(
+            tierlist.items_to_tiers,
+            vec![
+                (items[0].clone(), "".to_string()),
+                (items[1].clone(), "S".to_string())
+            ]
+        );
+        // Previews are never stored
+        assert!(TIERLISTS
+            .may_load(&deps.storage, (&Addr::unchecked(ADDR1), 0))
+            .unwrap()
+            .is_none());
+
+        // Unknown items and undefined tiers are rejected
+        preview(deps.as_ref(), vec![("C", "S")]).unwrap_err();
+        preview(deps.as_ref(), vec![("A", "Z")]).unwrap_err();
+    }
+
+    #[test]
+    fn test_template_categories() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::Categories {}).unwrap();
+        let res: CategoriesResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            res.categories,
+            vec!["Games", "Anime", "Food", "Sports", "Other"]
+        );
+
+        for title in ["Tierlist 1", "Tierlist 2", "Tierlist 3"] {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: title.to_string(),
+                items: vec![named_item("A")],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        let set_category = |id: u64, category: &str| ExecuteMsg::SetTemplateCategory {
+            id,
+            category: Some(category.to_string()),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            set_category(0, "Cars"),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InvalidCategory { category } if category == "Cars"
+        ));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            set_category(0, "Games"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        for id in [0, 2] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                set_category(id, "Games"),
+            )
+            .unwrap();
+        }
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            set_category(1, "Food"),
+        )
+        .unwrap();
+
+        let listed = |deps: Deps, category: &str| {
+            let msg = QueryMsg::Templates {
+                start_after: None,
+                limit: None,
+                open_only: None,
+                category: Some(category.to_string()),
+                language: None,
+                include_unspecified: None,
+                sort: None,
+            };
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        assert_eq!(listed(deps.as_ref(), "Games"), vec![0, 2]);
+        assert_eq!(listed(deps.as_ref(), "Food"), vec![1]);
+        assert!(listed(deps.as_ref(), "Anime").is_empty());
+
+        // Categories in use cannot be removed, only the admin may update them
+        let msg = ExecuteMsg::UpdateCategories {
+            add: vec!["Cars".to_string()],
+            remove: vec!["Food".to_string()],
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::CategoryInUse { category } if category == "Food"
+        ));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+
+        // Moving the template frees the category
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            set_category(1, "Games"),
+        )
+        .unwrap();
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert_eq!(listed(deps.as_ref(), "Games"), vec![0, 1, 2]);
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::Categories {}).unwrap();
+        let res: CategoriesResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            res.categories,
+            vec!["Games", "Anime", "Sports", "Other", "Cars"]
+        );
+
+        // Deleting a template drops it from the index
+        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+        assert_eq!(listed(deps.as_ref(), "Games"), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_query_tierlist_by_tiers() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = ["A", "B", "C", "D"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        for tier_definitions in [Some(vec!["S", "A", "B"]), None] {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: items.clone(),
+                tier_definitions: tier_definitions
+                    .map(|tiers| tiers.into_iter().map(TierDef::from).collect()),
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        for template_id in [0, 1] {
+            let tiers = ["B", "S", "", "S"];
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: items
+                        .iter()
+                        .cloned()
+                        .zip(tiers.iter().map(|tier| tier.to_string()))
+                        .collect(),
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+
+        let groups = |template_id: u64| {
+            let msg = QueryMsg::TierlistByTiers {
+                address: ADDR1.to_string(),
+                template_id,
+            };
+            let res: TierlistByTiersResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            res.tiers
+                .into_iter()
+                .map(|(tier, items)| {
+                    let names: Vec<_> = items.into_iter().map(|item| item.name).collect();
+                    (tier.name, names)
+                })
+                .collect::<Vec<_>>()
+        };
+        let group = |tier: &str, names: &[&str]| {
+            let names: Vec<_> = names.iter().map(|name| name.to_string()).collect();
+            (tier.to_string(), names)
+        };
+        // Declared order with unassigned last
+        assert_eq!(
+            groups(0),
+            vec![
+                group("S", &["B", "D"]),
+                group("A", &[]),
+                group("B", &["A"]),
+                group("", &["C"]),
+            ]
+        );
+        // Alphabetical without declared tiers
+        assert_eq!(
+            groups(1),
+            vec![
+                group("B", &["A"]),
+                group("S", &["B", "D"]),
+                group("", &["C"])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_languages() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let create = |language: Option<&str>| ExecuteMsg::CreateTemplate {
+            title: "Tierlist".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: language.map(String::from),
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        for language in ["EN", "e", "english", "en-", "12"] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                create(Some(language)),
+            )
+            .unwrap_err();
+            assert!(matches!(err, ContractError::InvalidLanguage { .. }));
+        }
+        for language in [Some("en"), Some("ko"), None, Some("en")] {
+            execute(deps.as_mut(), env.clone(), info.clone(), create(language)).unwrap();
+        }
+
+        let listed = |deps: Deps, language: Option<&str>, include_unspecified: bool| {
+            let msg = QueryMsg::Templates {
+                start_after: None,
+                limit: None,
+                open_only: None,
+                category: None,
+                language: language.map(String::from),
+                include_unspecified: Some(include_unspecified),
+                sort: None,
+            };
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        assert_eq!(listed(deps.as_ref(), None, false), vec![0, 1, 2, 3]);
+        assert_eq!(listed(deps.as_ref(), Some("en"), false), vec![0, 3]);
+        assert_eq!(listed(deps.as_ref(), Some("ko"), false), vec![1]);
+        assert_eq!(listed(deps.as_ref(), Some("ko"), true), vec![1, 2]);
+        assert!(listed(deps.as_ref(), Some("fr"), false).is_empty());
+
+        // Editing moves the template between languages
+        let msg = ExecuteMsg::EditTemplate {
+            id: 3,
+            title: "Tierlist".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            tier_definitions: None,
+            max_submissions: None,
+            language: Some("pt-br".to_string()),
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        assert_eq!(listed(deps.as_ref(), Some("en"), false), vec![0]);
+        assert_eq!(listed(deps.as_ref(), Some("pt-br"), false), vec![3]);
+
+        let msg = QueryMsg::SearchTemplates {
+            creator: Some(ADDR1.to_string()),
+            open_only: None,
+            language: Some("en".to_string()),
+            include_unspecified: Some(true),
+            start_after: None,
+            limit: None,
+            include_archived: None,
+            include_unlisted: None,
+        };
+        let res: SearchTemplatesResponse =
+            from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        let ids: Vec<_> = res.templates.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_strict_queries() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        // Lenient by default
+        for strict in [None, Some(false)] {
+            let msg = QueryMsg::Template { id: 3, strict };
+            let res: TemplateResponse =
+                from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+            assert_eq!(res.template, None);
+            let msg = QueryMsg::Tierlist {
+                address: ADDR1.to_string(),
+                id: 3,
+                strict,
+            };
+            let res: TierlistResponse =
+                from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+            assert_eq!(res.tierlist, None);
+            let msg = QueryMsg::TierlistFromTemplate { id: 3, strict };
+            let res: TierlistResponse =
+                from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+            assert_eq!(res.tierlist, None);
+        }
+
+        let msg = QueryMsg::Template {
+            id: 3,
+            strict: Some(true),
+        };
+        let err = query(deps.as_ref(), env.clone(), msg).unwrap_err();
+        assert!(matches!(err, StdError::NotFound { kind } if kind == "template 3"));
+        let msg = QueryMsg::TierlistFromTemplate {
+            id: 3,
+            strict: Some(true),
+        };
+        let err = query(deps.as_ref(), env.clone(), msg).unwrap_err();
+        assert!(matches!(err, StdError::NotFound { kind } if kind == "template 3"));
+        let msg = QueryMsg::Tierlist {
+            address: ADDR1.to_string(),
+            id: 3,
+            strict: Some(true),
+        };
+        let err = query(deps.as_ref(), env, msg).unwrap_err();
+        assert!(matches!(err, StdError::NotFound { .. }));
+    }
+
+    #[test]
+    fn test_item_alt_names() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = |alt_names: Vec<(&str, &str)>| TierlistItem {
+            name: "Charizard".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: Some(
+                alt_names
+                    .into_iter()
+                    .map(|(locale, name)| (locale.to_string(), name.to_string()))
+                    .collect(),
+            ),
+        };
+        let create = |item: TierlistItem| ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        let too_many = item(vec![("ja", "a"); MAX_ALT_NAMES + 1]);
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), create(too_many)).unwrap_err();
+        assert!(matches!(err, ContractError::TooManyAltNames { .. }));
+        let long_name = "a".repeat(MAX_ALT_NAME_LENGTH + 1);
+        let too_long = item(vec![("ja", &long_name)]);
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), create(too_long)).unwrap_err();
+        assert!(matches!(err, ContractError::AltNameTooLong { .. }));
+
+        let charizard = item(vec![("ja", "リザードン"), ("fr", "Dracaufeu")]);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(charizard.clone()),
+        )
+        .unwrap();
+
+        // Alternative names are not part of the item's identity
+        let plain = TierlistItem {
+            alt_names: None,
+            ..charizard.clone()
+        };
+        for tierlist_item in [charizard.clone(), plain] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(tierlist_item, "S".to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+
+        // Editing only the title leaves the items untouched
+        let msg = ExecuteMsg::PatchTemplate {
+            id: 0,
+            title: Some("Tierlist 2".to_string()),
+            items: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let msg = QueryMsg::Template {
+            id: 0,
+            strict: None,
+        };
+        let res: TemplateResponse = from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        assert_eq!(res.template.unwrap().items, vec![charizard]);
+    }
+
+    #[test]
+    fn test_query_templates_by_item_count() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        // Templates 0 to 3 have 1, 5, 10 and 11 items
+        for count in [1, 5, 10, 11] {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: (0..count)
+                    .map(|i| TierlistItem {
+                        name: i.to_string(),
+                        image_url: None,
+                        order: 0,
+                        alt_names: None,
+                    })
+                    .collect(),
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+
+        let by_count = |min_items: Option<u32>, max_items: Option<u32>, start_after| {
+            let msg = QueryMsg::TemplatesByItemCount {
+                min_items,
+                max_items,
+                start_after,
+                limit: None,
+            };
+            let bin = query(deps.as_ref(), mock_env(), msg)?;
+            let res: TemplatesByItemCountResponse = from_binary(&bin).unwrap();
+            Ok::<_, StdError>(
+                res.templates
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(by_count(None, None, None).unwrap(), vec![0, 1, 2, 3]);
+        assert_eq!(by_count(Some(5), Some(10), None).unwrap(), vec![1, 2]);
+        assert_eq!(by_count(Some(10), Some(10), None).unwrap(), vec![2]);
+        assert_eq!(by_count(Some(11), None, None).unwrap(), vec![3]);
+        assert_eq!(by_count(None, Some(4), None).unwrap(), vec![0]);
+        assert_eq!(by_count(Some(5), None, Some(1)).unwrap(), vec![2, 3]);
+        let err = by_count(Some(10), Some(5), None).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Invalid item count range, min 10 is above max 5")
+        );
+
+        // The limit bounds templates scanned, not matched
+        let msg = QueryMsg::TemplatesByItemCount {
+            min_items: Some(10),
+            max_items: None,
+            start_after: None,
+            limit: Some(2),
+        };
+        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let res: TemplatesByItemCountResponse = from_binary(&bin).unwrap();
+        assert_eq!(res.templates, vec![]);
+        assert_eq!(res.last_scanned, Some(1));
+    }
+
+    #[test]
+    fn test_query_aggregate_rankings() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items: Vec<_> = (0..100)
+            .map(|i| TierlistItem {
+                name: format!("item{:03}", i),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        for (addr, tier) in [(ADDR1, "S"), (ADDR2, "A")] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: items
+                        .iter()
+                        .map(|item| (item.clone(), tier.to_string()))
+                        .collect(),
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let aggregate = |item_start_after: Option<String>, item_limit| {
+            let msg = QueryMsg::AggregateRankings {
+                template_id: 0,
+                start_after: None,
+                limit: None,
+                item_start_after,
+                item_limit,
+            };
+            let res: AggregateRankingsResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            res
+        };
+        let res = aggregate(None, Some(20));
+        assert_eq!(res.submissions, 2);
+        assert_eq!(res.items.len(), 20);
+        assert_eq!(res.items[0].name, "item000");
+        assert_eq!(
+            res.items[0].distribution,
+            vec![("A".to_string(), 1), ("S".to_string(), 1)]
+        );
+        assert_eq!(res.last_item, Some("item019".to_string()));
+
+        let res = aggregate(res.last_item, Some(20));
+        assert_eq!(res.items.first().unwrap().name, "item020");
+        assert_eq!(res.items.len(), 20);
+        // The item limit is capped
+        assert_eq!(aggregate(None, Some(1000)).items.len(), 30);
+        let res = aggregate(Some("item095".to_string()), None);
+        assert_eq!(res.items.len(), 4);
+        assert_eq!(res.last_item, Some("item099".to_string()));
+
+        // Submitters page separately from the items
+        for (start_after, tier, last) in [(None, "S", ADDR1), (Some(ADDR1), "A", ADDR2)] {
+            let msg = QueryMsg::AggregateRankings {
+                template_id: 0,
+                start_after: start_after.map(String::from),
+                limit: Some(1),
+                item_start_after: None,
+                item_limit: Some(1),
+            };
+            let res: AggregateRankingsResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            assert_eq!(res.submissions, 1);
+            assert_eq!(res.items[0].distribution, vec![(tier.to_string(), 1)]);
+            assert_eq!(res.last_address, Some(Addr::unchecked(last)));
+        }
+    }
+
+    #[test]
+    fn test_tier_display_metadata() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let tier = |color: &str, emoji: &str| TierDef {
+            name: "S".to_string(),
+            color: Some(color.to_string()),
+            emoji: Some(emoji.to_string()),
+        };
+        let edit = |tier: TierDef| ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: Some(vec![tier]),
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: Some(vec![tier("#ff7f7f", "🔥")]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        for color in ["ff7f7f", "#ff7f7", "#gg7f7f", "#ff7f7f0"] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                edit(tier(color, "🔥")),
+            )
+            .unwrap_err();
+            assert!(matches!(err, ContractError::InvalidTierColor { .. }));
+        }
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            edit(tier("#ff7f7f", "🔥🔥🔥")),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TierEmojiTooLong { max: 8, .. }
+        ));
+
+        // Assignments only reference the tier name
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item.clone(), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+
+        // Recoloring keeps the saved tierlist valid
+        let recolored = tier("#7fbfff", "⭐");
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            edit(recolored.clone()),
+        )
+        .unwrap();
+        let sync = ExecuteMsg::SyncTierlist { template_id: 0 };
+        execute(deps.as_mut(), env.clone(), info.clone(), sync).unwrap();
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        // Groups carry the full tier definition
+        let msg = QueryMsg::TierlistByTiers {
+            address: ADDR1.to_string(),
+            template_id: 0,
+        };
+        let res: TierlistByTiersResponse =
+            from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        assert_eq!(res.tiers, vec![(recolored, vec![item])]);
+    }
+
+    #[test]
+    fn test_archive_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        for title in ["Tierlist 1", "Tierlist 2"] {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: title.to_string(),
+                items: vec![named_item("A")],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        let save = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(named_item("A"), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), save.clone()).unwrap();
+
+        let archive = ExecuteMsg::ArchiveTemplate { id: 0 };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            archive.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(deps.as_mut(), env.clone(), info.clone(), archive).unwrap();
+
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), save.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateArchived {}));
+        // The template and its tierlists stay readable
+        let msg = QueryMsg::Tierlist {
+            address: ADDR1.to_string(),
+            id: 0,
+            strict: Some(true),
+        };
+        query(deps.as_ref(), env.clone(), msg).unwrap();
+        let msg = QueryMsg::Template {
+            id: 0,
+            strict: Some(true),
+        };
+        query(deps.as_ref(), env.clone(), msg).unwrap();
+        // Syncing only catches up with template edits, so it is still allowed
+        let sync = ExecuteMsg::SyncTierlist { template_id: 0 };
+        execute(deps.as_mut(), env.clone(), info.clone(), sync).unwrap();
+
+        let listed = |deps: Deps, msg: QueryMsg| {
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        let archived = QueryMsg::ArchivedTemplates {
+            start_after: None,
+            limit: None,
+        };
+        let templates = QueryMsg::Templates {
+            start_after: None,
+            limit: None,
+            open_only: None,
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
+        };
+        assert_eq!(listed(deps.as_ref(), archived.clone()), vec![0]);
+        assert_eq!(listed(deps.as_ref(), templates.clone()), vec![1]);
+
+        let msg = ExecuteMsg::RestoreTemplate { id: 0 };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert!(listed(deps.as_ref(), archived).is_empty());
+        assert_eq!(listed(deps.as_ref(), templates), vec![0, 1]);
+        execute(deps.as_mut(), env, info, save).unwrap();
+    }
+
+    #[test]
+    fn test_query_has_tierlist() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![named_item("A")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(named_item("A"), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+
+        let has_tierlist = |address: &str, template_id: u64| {
+            let msg = QueryMsg::HasTierlist {
+                address: address.to_string(),
+                template_id,
+            };
+            let res: HasTierlistResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg)?).unwrap();
+            Ok::<_, StdError>(res.exists)
+        };
+        assert!(has_tierlist(ADDR1, 0).unwrap());
+        // Addresses are normalized like everywhere else
+        assert!(has_tierlist(&ADDR1.to_uppercase(), 0).unwrap());
+        assert!(!has_tierlist(ADDR2, 0).unwrap());
+        assert!(!has_tierlist(ADDR1, 1).unwrap());
+        has_tierlist("", 0).unwrap_err();
+    }
+
+    #[test]
+    fn test_consensus_follows_tier_order() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let create = |tiers: Vec<&str>| ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: Some(tiers.into_iter().map(TierDef::from).collect()),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(vec!["S", "A", "S"]),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateTier { name } if name == "S"));
+
+        // "C" is declared above "B", alphabetical order would pick "B"
+        execute(deps.as_mut(), env.clone(), info, create(vec!["C", "B"])).unwrap();
+        for (addr, tier) in [(ADDR1, "B"), (ADDR2, "C")] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(item.clone(), tier.to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let msg = QueryMsg::AggregateRankings {
+            template_id: 0,
+            start_after: None,
+            limit: None,
+            item_start_after: None,
+            item_limit: None,
+        };
+        let res: AggregateRankingsResponse =
+            from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        assert_eq!(res.items[0].consensus, Some("C".to_string()));
+    }
+
+    #[test]
+    fn test_anonymous_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+        let msg = ExecuteMsg::CreateAnonymousTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 2".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        // Archived anonymous templates are left out like in the other listings
+        let msg = ExecuteMsg::CreateAnonymousTemplate {
+            title: "Tierlist 3".to_string(),
+            items: items.clone(),
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let msg = ExecuteMsg::ArchiveTemplate { id: 2 };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        // So are unlisted ones
+        let msg = ExecuteMsg::CreateAnonymousTemplate {
+            title: "Tierlist 4".to_string(),
+            items: items.clone(),
+            visibility: Some(TemplateVisibility::Unlisted),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let template = TIERLIST_TEMPLATES.load(&deps.storage, 3).unwrap();
+        assert_eq!(template.visibility, TemplateVisibility::Unlisted);
+
+        let msg = QueryMsg::AnonymousTemplates {
+            start_after: None,
+            limit: None,
+        };
+        let res: Vec<(u64, TierlistTemplate)> =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].0, 0);
+        assert_eq!(res[0].1.creator, Addr::unchecked(""));
+
+        // The caller that created it has no claim over it
+        let msg = ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "NewTitle".to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            ExecuteMsg::DeleteTemplate { id: 0 },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            ExecuteMsg::DeleteTemplate { id: 0 },
+        )
+        .unwrap();
+        let msg = QueryMsg::AnonymousTemplates {
+            start_after: None,
+            limit: None,
+        };
+        let res: Vec<(u64, TierlistTemplate)> =
+            from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        assert!(res.is_empty());
+    }
+
+    #[test]
+    fn test_save_tierlist_for() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        // Fixed keys keep the signatures deterministic
+        let key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+        let other_key = SigningKey::from_bytes(&[9u8; 32]).unwrap();
+        let pubkey = Binary::from(key.verifying_key().to_bytes().as_slice());
+        let hash = Ripemd160::digest(&Sha256::digest(&pubkey));
+        let owner = bech32::encode("cosmos", hash.to_base32(), Variant::Bech32).unwrap();
+
+        let tierlist = |tier: &str| Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![(item.clone(), tier.to_string())],
+        };
+        let sign = |key: &SigningKey, tierlist: &Tierlist, nonce| {
+            let permit = SavePermit {
+                contract_address: env.contract.address.to_string(),
+                chain_id: env.block.chain_id.clone(),
+                template_id: tierlist.template_id,
+                tierlist_digest: Binary::from(
+                    Sha256::digest(&to_binary(tierlist).unwrap()).as_slice(),
+                ),
+                nonce,
+            };
+            let signature: Signature = key.sign(&to_binary(&permit).unwrap());
+            Binary::from(signature.as_ref())
+        };
+        let save = |tierlist: Tierlist, signature, pubkey, nonce| ExecuteMsg::SaveTierlistFor {
+            owner: owner.clone(),
+            tierlist,
+            signature,
+            pubkey,
+            nonce,
+        };
+
+        // Tampered payloads, foreign keys and skipped nonces are all rejected
+        let msg = save(
+            tierlist("B"),
+            sign(&key, &tierlist("A"), 0),
+            pubkey.clone(),
+            0,
+        );
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSignature {}));
+        let other_pubkey = Binary::from(other_key.verifying_key().to_bytes().as_slice());
+        let msg = save(
+            tierlist("A"),
+            sign(&other_key, &tierlist("A"), 0),
+            other_pubkey,
+            0,
+        );
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::PubkeyMismatch { owner: o } if o == owner));
+        let msg = save(
+            tierlist("A"),
+            sign(&key, &tierlist("A"), 1),
+            pubkey.clone(),
+            1,
+        );
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InvalidNonce {
+                expected: 0,
+                got: 1
+            }
+        ));
+
+        let msg = save(
+            tierlist("A"),
+            sign(&key, &tierlist("A"), 0),
+            pubkey.clone(),
+            0,
+        );
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        let saved = TIERLISTS
+            .load(&deps.storage, (&Addr::unchecked(&owner), 0))
+            .unwrap();
+        assert_eq!(saved.items_to_tiers[0].1, "A");
+        assert!(!TIERLISTS.has(&deps.storage, (&Addr::unchecked(ADDR2), 0)));
+
+        // Replaying the permit fails once its nonce is used
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InvalidNonce {
+                expected: 1,
+                got: 0
+            }
+        ));
+        let msg = save(tierlist("B"), sign(&key, &tierlist("B"), 1), pubkey, 1);
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap();
+    }
+
+    #[test]
+    fn test_clone_template_lineage() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            tier_definitions: Some(vec!["S".into(), "A".into()]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let clone = |id| ExecuteMsg::CloneTemplate {
+            id,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), clone(0)).unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), clone(1)).unwrap();
+
+        let source = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        let fork = TIERLIST_TEMPLATES.load(&deps.storage, 1).unwrap();
+        assert_eq!(source.forked_from, None);
+        assert_eq!(fork.forked_from, Some(0));
+        assert_eq!(fork.creator, Addr::unchecked(ADDR2));
+        assert_eq!(fork.items, source.items);
+        assert_eq!(fork.tier_definitions, source.tier_definitions);
+        let forks = |deps: Deps, id| -> Vec<u64> {
+            let msg = QueryMsg::TemplateForks { id };
+            let res: TemplateForksResponse =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.forks
+        };
+        assert_eq!(forks(deps.as_ref(), 0), vec![1]);
+        assert_eq!(forks(deps.as_ref(), 1), vec![2]);
+        assert_eq!(forks(deps.as_ref(), 2), Vec::<u64>::new());
+
+        // Clones keep the source's visibility unless given one
+        let msg = ExecuteMsg::CloneTemplate {
+            id: 0,
+            visibility: Some(TemplateVisibility::Unlisted),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), clone(3)).unwrap();
+        let fork = TIERLIST_TEMPLATES.load(&deps.storage, 4).unwrap();
+        assert_eq!(fork.visibility, TemplateVisibility::Unlisted);
+
+        let msg = ExecuteMsg::DeleteTemplate { id: 1 };
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap();
+        assert_eq!(forks(deps.as_ref(), 0), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_tierlist_fingerprint() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let items: Vec<_> = ["A", "B"]
+            .iter()
+            .map(|name| TierlistItem {
+                name: name.to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        // Same assignments submitted in opposite orders
+        let save = |order: Vec<(usize, &str)>| ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: order
+                    .into_iter()
+                    .map(|(i, tier)| (items[i].clone(), tier.to_string()))
+                    .collect(),
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        let msg = save(vec![(0, "S"), (1, "A")]);
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        let msg = save(vec![(1, "A"), (0, "S")]);
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let fingerprint = |deps: Deps, address: &str| {
+            let msg = QueryMsg::TierlistFingerprint {
+                address: address.to_string(),
+                id: 0,
+            };
+            let res: TierlistFingerprintResponse =
+                from_binary(&query(deps, mock_env(), msg)?).unwrap();
+            Ok::<_, StdError>(res.fingerprint)
+        };
+        let first = fingerprint(deps.as_ref(), ADDR1).unwrap();
+        assert_eq!(first.len(), 64);
+        assert_eq!(first, fingerprint(deps.as_ref(), ADDR2).unwrap());
+
+        let msg = save(vec![(0, "A"), (1, "S")]);
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap();
+        assert_ne!(first, fingerprint(deps.as_ref(), ADDR2).unwrap());
+        assert!(fingerprint(deps.as_ref(), "addr3").is_err());
+    }
+
+    #[test]
+    fn test_save_permit_digest() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+        let pubkey = Binary::from(key.verifying_key().to_bytes().as_slice());
+        let hash = Ripemd160::digest(&Sha256::digest(&pubkey));
+        let owner = bech32::encode("cosmos", hash.to_base32(), Variant::Bech32).unwrap();
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![(item, "S".to_string())],
+        };
+        let nonce = |deps: Deps| {
+            let msg = QueryMsg::Nonce {
+                address: owner.clone(),
+            };
+            let res: NonceResponse = from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.nonce
+        };
+        assert_eq!(nonce(deps.as_ref()), 0);
+
+        for expected in 0..2 {
+            let msg = QueryMsg::SavePermitDigest {
+                owner: owner.clone(),
+                tierlist: tierlist.clone(),
+            };
+            let digest: Binary =
+                from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+            let permit = SavePermit {
+                contract_address: env.contract.address.to_string(),
+                chain_id: env.block.chain_id.clone(),
+                template_id: 0,
+                tierlist_digest: Binary::from(
+                    Sha256::digest(&to_binary(&tierlist).unwrap()).as_slice(),
+                ),
+                nonce: expected,
+            };
+            let signature: Signature = key.sign(&to_binary(&permit).unwrap());
+            // The signature made over the permit verifies against the queried digest
+            assert!(deps
+                .api
+                .secp256k1_verify(&digest, signature.as_ref(), &pubkey)
+                .unwrap());
+
+            let msg = ExecuteMsg::SaveTierlistFor {
+                owner: owner.clone(),
+                tierlist: tierlist.clone(),
+                signature: Binary::from(signature.as_ref()),
+                pubkey: pubkey.clone(),
+                nonce: expected,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+            assert_eq!(nonce(deps.as_ref()), expected + 1);
+        }
+    }
+
+    #[test]
+    fn test_lock_tierlist() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let lock = |owner: Option<&str>| ExecuteMsg::LockTierlist {
+            template_id: 0,
+            owner: owner.map(String::from),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            lock(None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TierlistNotFound { .. }));
+
+        let save = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item, "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            save.clone(),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            lock(None),
+        )
+        .unwrap();
+        let stored = TIERLISTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR2), 0))
+            .unwrap();
+        assert!(stored.locked);
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            save.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TierlistLocked {}));
+        let msg = ExecuteMsg::SyncTierlist { template_id: 0 };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::TierlistLocked {}));
+
+        // Only the admin may lock or unlock someone else's tierlist
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("addr3", &[]),
+            lock(Some(ADDR2)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        let msg = ExecuteMsg::UnlockTierlist {
+            template_id: 0,
+            owner: Some(ADDR2.to_string()),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), save).unwrap();
+    }
+
+    #[test]
+    fn test_export_records() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: Some(vec!["S".into()]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item, "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+
+        let msg = QueryMsg::ExportTemplate { id: 0 };
+        let encoded: Binary =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        let template = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        assert_eq!(decode_template(&encoded).unwrap(), template);
+
+        let msg = QueryMsg::ExportTierlist {
+            address: ADDR2.to_string(),
+            template_id: 0,
+        };
+        let encoded: Binary =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        let tierlist = TIERLISTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR2), 0))
+            .unwrap();
+        assert_eq!(decode_tierlist(&encoded).unwrap(), tierlist);
+
+        let msg = QueryMsg::ExportTemplate { id: 1 };
+        assert!(query(deps.as_ref(), env, msg).is_err());
+    }
+
+    #[test]
+    fn test_import_template_from_json() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            tier_definitions: Some(vec!["S".into(), "A".into()]),
+            max_submissions: Some(3),
+            language: Some("en".to_string()),
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        // A template exported as JSON imports as a copy owned by the sender
+        let source = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        let json = String::from_utf8(to_binary(&source).unwrap().to_vec()).unwrap();
+        let msg = ExecuteMsg::ImportTemplateFromJSON {
+            json: json.clone(),
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let imported = TIERLIST_TEMPLATES.load(&deps.storage, 1).unwrap();
+        assert_eq!(imported.id, 1);
+        assert_eq!(imported.creator, Addr::unchecked(ADDR2));
+        assert_eq!(imported.title, source.title);
+        assert_eq!(imported.items, source.items);
+        assert_eq!(imported.tier_definitions, source.tier_definitions);
+        assert_eq!(imported.max_submissions, Some(3));
+        assert_eq!(imported.language, Some("en".to_string()));
+        assert_eq!(imported.content_hash, source.content_hash);
+        assert_eq!(imported.visibility, TemplateVisibility::Public);
+        let msg = ExecuteMsg::ImportTemplateFromJSON {
+            json,
+            visibility: Some(TemplateVisibility::Unlisted),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let imported = TIERLIST_TEMPLATES.load(&deps.storage, 2).unwrap();
+        assert_eq!(imported.visibility, TemplateVisibility::Unlisted);
+
+        let msg = ExecuteMsg::ImportTemplateFromJSON {
+            json: r#"{"items": []}"#.to_string(),
+            visibility: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTemplateJson { .. }));
+
+        // Parsed templates are validated like CreateTemplate
+        let msg = ExecuteMsg::ImportTemplateFromJSON {
+            json: r#"{"title": "T", "items": []}"#.to_string(),
+            visibility: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::NoItems {}));
+        let json = r#"{"title": "T", "items": [{"name": "A", "image_url": null}], "tier_definitions": ["S", "S"]}"#;
+        let msg = ExecuteMsg::ImportTemplateFromJSON {
+            json: json.to_string(),
+            visibility: None,
+        };
+        let err = execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateTier { .. }));
+    }
+
+    #[test]
+    fn test_participant_count() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let participants = |deps: Deps| {
+            let msg = QueryMsg::ParticipantCount { template_id: 0 };
+            let res: ParticipantCountResponse =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.count
+        };
+        assert_eq!(participants(deps.as_ref()), 0);
+
+        let save = |tier: &str| ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item.clone(), tier.to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), save("S")).unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), save("A")).unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), save("S")).unwrap();
+        assert_eq!(participants(deps.as_ref()), 2);
+
+        let msg = ExecuteMsg::AdminDeleteTierlist {
+            address: ADDR2.to_string(),
+            template_id: 0,
+        };
+        execute(deps.as_mut(), env, mock_info(ADDR1, &[]), msg).unwrap();
+        assert_eq!(participants(deps.as_ref()), 1);
+    }
+
+    #[test]
+    fn test_create_template_from_remote() {
+        let mut app = App::default();
+        let code_id = app.store_code(Box::new(ContractWrapper::new(execute, instantiate, query)));
+        let instantiate_msg = InstantiateMsg {
+            admin_address: ADDR1.to_string(),
+            require_template_tiers: false,
+            hide_creators: false,
+            max_tierlist_bytes: None,
+        };
+        let mut instances: Vec<_> = (0..2)
+            .map(|_| {
+                app.instantiate_contract(
+                    code_id,
+                    Addr::unchecked(ADDR1),
+                    &instantiate_msg,
+                    &[],
+                    "tierlist",
+                    None,
+                )
+                .unwrap()
+            })
+            .collect();
+        let (local, remote) = (instances.pop().unwrap(), instances.pop().unwrap());
+
+        let items: Vec<_> = ["A", "B"]
+            .iter()
+            .enumerate()
+            .map(|(order, name)| TierlistItem {
+                name: name.to_string(),
+                image_url: Some(format!("https://example.com/{}.png", name)),
+                order: order as u32,
+                alt_names: None,
+            })
+            .collect();
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Catalog".to_string(),
+            items: items.clone(),
+            tier_definitions: Some(vec!["S".into()]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        app.execute_contract(Addr::unchecked(ADDR1), remote.clone(), &msg, &[])
+            .unwrap();
+
+        let copy = |template_id| ExecuteMsg::CreateTemplateFromRemote {
+            title: "Local".to_string(),
+            contract: remote.to_string(),
+            template_id,
+            visibility: None,
+        };
+        app.execute_contract(Addr::unchecked(ADDR2), local.clone(), &copy(0), &[])
+            .unwrap();
+        let res: TemplateResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &local,
+                &QueryMsg::Template {
+                    id: 0,
+                    strict: None,
+                },
+            )
+            .unwrap();
+        let template = res.template.unwrap();
+        assert_eq!(template.title, "Local");
+        assert_eq!(template.items, items);
+        assert_eq!(template.creator, Addr::unchecked(ADDR2));
+        assert_eq!(template.tier_definitions, None);
+        assert_eq!(template.source, Some((remote.to_string(), 0)));
+
+        let err = app
+            .execute_contract(Addr::unchecked(ADDR2), local.clone(), &copy(1), &[])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::RemoteTemplateNotFound { id: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_save_tierlist_without_replace() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let save = |tier: &str, replace| ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item.clone(), tier.to_string())],
+            },
+            replace,
+            idempotency_key: None,
+            force: None,
+        };
+        let info = mock_info(ADDR2, &[]);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            save("S", Some(false)),
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            save("A", Some(false)),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TierlistAlreadyExists { template_id: 0 }
+        ));
+        let stored = TIERLISTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR2), 0))
+            .unwrap();
+        assert_eq!(stored.items_to_tiers[0].1, "S");
+
+        // Omitting the flag keeps overwriting as before
+        execute(deps.as_mut(), env.clone(), info.clone(), save("A", None)).unwrap();
+        execute(deps.as_mut(), env, info, save("B", Some(true))).unwrap();
+        let stored = TIERLISTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR2), 0))
+            .unwrap();
+        assert_eq!(stored.items_to_tiers[0].1, "B");
+    }
+
+    #[test]
+    fn test_migrate_normalize_addresses() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+
+        // Records as the first release stored them, under unnormalized creators and owners
+        deps.storage.set(b"config", br#"{"admin_address":"addr1"}"#);
+        deps.storage.set(b"next_id", b"2");
+        for (id, creator) in [(0, "ADDR1"), (1, ADDR1)] {
+            let template = format!(
+                r#"{{"id":{},"title":"Tierlist {}","items":[{{"name":"A","image_url":null}}],"creator":"{}"}}"#,
+                id, id, creator
+            );
+            deps.storage
+                .set(&raw::template_key(id), template.as_bytes());
+        }
+        let legacy_creator = Addr::unchecked("ADDR1");
+        let legacy_owner = Addr::unchecked("Addr2");
+        for template_id in 0..2 {
+            let tierlist = format!(
+                r#"{{"template_id":{},"items_to_tiers":[[{{"name":"A","image_url":null}},"S"]]}}"#,
+                template_id
+            );
+            deps.storage.set(
+                &raw::tierlist_key(&legacy_owner, template_id),
+                tierlist.as_bytes(),
+            );
+        }
+        TIERLIST_COMMENTS
+            .save(&mut deps.storage, (&legacy_owner, 1), &"Nice".to_string())
+            .unwrap();
+
+        let status = |deps: Deps| -> MigrationStatus {
+            from_binary(&query(deps, mock_env(), QueryMsg::MigrationStatus {}).unwrap()).unwrap()
+        };
+        assert_eq!(status(deps.as_ref()), MigrationStatus::default());
+
+        // Both templates and the first tierlist fit in the first pass
+        let msg = MigrateMsg::NormalizeAddresses { limit: 3 };
+        migrate(deps.as_mut(), env.clone(), msg.clone()).unwrap();
+        let progress = status(deps.as_ref());
+        assert!(progress.templates_done);
+        assert!(!progress.tierlists_done);
+        assert_eq!(progress.rewritten, 2);
+        migrate(deps.as_mut(), env, msg).unwrap();
+        let progress = status(deps.as_ref());
+        assert!(progress.tierlists_done);
+        assert_eq!(progress.rewritten, 3);
+        assert_eq!(progress.skipped, 0);
+
+        // The admin kept in the old config became the owner
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::Ownership {}).unwrap();
+        let ownership: Ownership = from_binary(&bin).unwrap();
+        assert_eq!(ownership.owner, Some(Addr::unchecked(ADDR1)));
+
+        let template = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+        assert_eq!(template.creator, Addr::unchecked(ADDR1));
+        assert!(template.submissions_open);
+        for id in 0..2 {
+            assert!(TEMPLATES_BY_CREATOR.has(&deps.storage, (&Addr::unchecked(ADDR1), id)));
+            assert!(!TEMPLATES_BY_CREATOR.has(&deps.storage, (&legacy_creator, id)));
+        }
+        for template_id in 0..2 {
+            let msg = QueryMsg::Tierlist {
+                address: ADDR2.to_string(),
+                id: template_id,
+                strict: None,
+            };
+            let res: TierlistResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            assert!(res.tierlist.is_some());
+            assert!(!TIERLISTS.has(&deps.storage, (&legacy_owner, template_id)));
+        }
+        let msg = QueryMsg::TierlistsForTemplate {
+            template_id: 1,
+            start_after_address: None,
+            limit: None,
+            include_private: true,
+        };
+        let res: TierlistsForTemplateResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res.tierlists.len(), 1);
+        assert_eq!(res.tierlists[0].0, ADDR2);
+        let comment = TIERLIST_COMMENTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR2), 1))
+            .unwrap();
+        assert_eq!(comment, "Nice");
+    }
+
+    #[test]
+    fn test_tierlist_history() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let count = |deps: Deps| -> u64 {
+            let msg = QueryMsg::TierlistHistoryCount {
+                address: ADDR2.to_string(),
+                template_id: 0,
+            };
+            from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap()
+        };
+        assert_eq!(count(deps.as_ref()), 0);
+
+        for tier in ["S", "A", "B"] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(item.clone(), tier.to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        }
+        assert_eq!(count(deps.as_ref()), 3);
+        // Each save snapshots the record it replaces
+        let snapshot = TIERLIST_HISTORY
+            .load(&deps.storage, (&Addr::unchecked(ADDR2), 0, 1))
+            .unwrap();
+        assert_eq!(snapshot.items_to_tiers[0].1, "S");
+        assert!(!TIERLIST_HISTORY.has(&deps.storage, (&Addr::unchecked(ADDR2), 0, 3)));
+
+        let msg = QueryMsg::TierlistHistory {
+            address: ADDR2.to_string(),
+            template_id: 0,
+            start_after_version: Some(1),
+            limit: Some(1),
+        };
+        let history: Vec<(u64, Tierlist)> =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].0, 2);
+        assert_eq!(history[0].1.items_to_tiers[0].1, "A");
+
+        let msg = ExecuteMsg::ClearTierlistHistory {
+            template_id: 0,
+            keep_latest: Some(1),
+        };
+        let res = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let deleted = res.attributes.iter().find(|a| a.key == "deleted").unwrap();
+        assert_eq!(deleted.value, "1");
+        // The revision count is not affected by pruning snapshots
+        assert_eq!(count(deps.as_ref()), 3);
+        // The latest snapshot is the one kept
+        let msg = QueryMsg::TierlistRevision {
+            address: ADDR2.to_string(),
+            template_id: 0,
+            revision: 2,
+        };
+        let res: TierlistResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(res.tierlist.unwrap().items_to_tiers[0].1, "A");
+
+        let clear = ExecuteMsg::AdminClearTierlistHistory {
+            address: ADDR2.to_string(),
+            template_id: 0,
+            keep_latest: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            clear.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        let res = execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), clear).unwrap();
+        let deleted = res.attributes.iter().find(|a| a.key == "deleted").unwrap();
+        assert_eq!(deleted.value, "1");
+
+        // Revision numbers keep increasing after the tierlist is deleted and saved again
+        let msg = ExecuteMsg::AdminDeleteTierlist {
+            address: ADDR2.to_string(),
+            template_id: 0,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        assert_eq!(count(deps.as_ref()), 3);
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item, "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap();
+        assert_eq!(count(deps.as_ref()), 4);
+    }
+
+    #[test]
+    fn test_update_config() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let update = ExecuteMsg::UpdateConfig {
+            require_template_tiers: None,
+            hide_creators: Some(true),
+            max_tierlist_bytes: None,
+            min_items_per_template: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            update.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), update).unwrap();
+
+        let bin = query(deps.as_ref(), env, QueryMsg::Config {}).unwrap();
+        let config: ConfigResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            config,
+            ConfigResponse {
+                admin_address: Some(ADDR1.to_string()),
+                require_template_tiers: false,
+                hide_creators: true,
+                max_tierlist_bytes: DEFAULT_MAX_TIERLIST_BYTES,
+                min_items_per_template: None,
+                contract_name: CONTRACT_NAME.to_string(),
+                contract_version: CONTRACT_VERSION.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_update_ownership() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let ownership = |deps: Deps| -> Ownership {
+            from_binary(&query(deps, mock_env(), QueryMsg::Ownership {}).unwrap()).unwrap()
+        };
+        assert_eq!(ownership(deps.as_ref()).owner, Some(Addr::unchecked(ADDR1)));
+
+        let transfer = ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+            new_owner: ADDR2.to_string(),
+            expiry: None,
+        });
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            transfer.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        let res = execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), transfer).unwrap();
+        assert_eq!(res.attributes[0], attr("action", "update_ownership"));
+        assert_eq!(res.attributes[2], attr("pending_owner", ADDR2));
+        assert_eq!(
+            ownership(deps.as_ref()).pending_owner,
+            Some(Addr::unchecked(ADDR2))
+        );
+
+        // The admin only changes once the transfer is accepted
+        let pin = ExecuteMsg::BanAddress {
+            address: ADDR3.to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            pin.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        let accept = ExecuteMsg::UpdateOwnership(Action::AcceptOwnership);
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), accept).unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            pin.clone(),
+        )
+        .unwrap();
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap();
+        let config: ConfigResponse = from_binary(&bin).unwrap();
+        assert_eq!(config.admin_address, Some(ADDR2.to_string()));
+
+        // Once renounced nobody may act as the admin
+        let renounce = ExecuteMsg::UpdateOwnership(Action::RenounceOwnership);
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), renounce).unwrap();
+        assert_eq!(ownership(deps.as_ref()), Ownership::default());
+        let err = execute(deps.as_mut(), env, mock_info(ADDR2, &[]), pin).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NoOwner {})
+        ));
+    }
+
+    #[test]
+    fn test_templates_created_between() {
+        let mut env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        // Templates 0, 1 and 2 at 100, 200 and 200 seconds
+        for (i, seconds) in [100, 200, 200].iter().enumerate() {
+            env.block.time = Timestamp::from_seconds(*seconds);
+            let msg = ExecuteMsg::CreateTemplate {
+                title: format!("Tierlist {}", i),
+                items: vec![TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                }],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        }
+
+        let between = |deps: Deps, start, end, start_after, limit| -> Vec<u64> {
+            let msg = QueryMsg::TemplatesCreatedBetween {
+                start: Timestamp::from_seconds(start),
+                end: Timestamp::from_seconds(end),
+                start_after,
+                limit,
+            };
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect()
+        };
+        assert_eq!(between(deps.as_ref(), 0, 1000, None, None), vec![0, 1, 2]);
+        // Start is inclusive, end is exclusive
+        assert_eq!(between(deps.as_ref(), 100, 200, None, None), vec![0]);
+        assert_eq!(between(deps.as_ref(), 200, 201, None, None), vec![1, 2]);
+        assert_eq!(
+            between(deps.as_ref(), 101, 200, None, None),
+            Vec::<u64>::new()
+        );
+        assert_eq!(
+            between(deps.as_ref(), 300, 400, None, None),
+            Vec::<u64>::new()
+        );
+        assert_eq!(
+            between(deps.as_ref(), 200, 200, None, None),
+            Vec::<u64>::new()
+        );
+        assert_eq!(
+            between(deps.as_ref(), 400, 100, None, None),
+            Vec::<u64>::new()
+        );
+
+        // Paging by (seconds, id), cursors before the window are ignored
+        assert_eq!(between(deps.as_ref(), 0, 1000, None, Some(2)), vec![0, 1]);
+        assert_eq!(
+            between(deps.as_ref(), 0, 1000, Some((200, 1)), None),
+            vec![2]
+        );
+        assert_eq!(
+            between(deps.as_ref(), 150, 1000, Some((100, 0)), None),
+            vec![1, 2]
+        );
+        assert_eq!(
+            between(deps.as_ref(), 0, 1000, Some((200, 2)), None),
+            Vec::<u64>::new()
+        );
+
+        let msg = QueryMsg::Template {
+            id: 0,
+            strict: None,
+        };
+        let res: TemplateResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(
+            res.template.unwrap().created_at,
+            Some(Timestamp::from_seconds(100))
+        );
+
+        // Deleting drops the template from the index
+        let msg = ExecuteMsg::DeleteTemplate { id: 1 };
+        execute(deps.as_mut(), env, mock_info(ADDR1, &[]), msg).unwrap();
+        assert_eq!(between(deps.as_ref(), 200, 201, None, None), vec![2]);
+    }
+
+    #[test]
+    fn test_templates_containing_item() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let make_items = |names: &[&str]| -> Vec<TierlistItem> {
+            names
+                .iter()
+                .map(|name| TierlistItem {
+                    name: name.to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                })
+                .collect()
+        };
+        for names in [["A", "B"], ["C", "D"], ["D", "A"]] {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: make_items(&names),
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        }
+
+        let containing = |deps: Deps, name: &str, start_after| -> Vec<u64> {
+            let msg = QueryMsg::TemplatesContainingItem {
+                item_name: name.to_string(),
+                start_after,
+                limit: None,
+            };
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect()
+        };
+        assert_eq!(containing(deps.as_ref(), "A", None), vec![0, 2]);
+        assert_eq!(containing(deps.as_ref(), "A", Some(0)), vec![2]);
+        assert_eq!(containing(deps.as_ref(), "E", None), Vec::<u64>::new());
+
+        // Edits move the index entries, deletes drop them
+        let msg = ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "Tierlist".to_string(),
+            items: make_items(&["E"]),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        assert_eq!(containing(deps.as_ref(), "A", None), vec![2]);
+        assert_eq!(containing(deps.as_ref(), "E", None), vec![0]);
+
+        let msg = ExecuteMsg::DeleteTemplate { id: 2 };
+        execute(deps.as_mut(), env, mock_info(ADDR1, &[]), msg).unwrap();
+        assert_eq!(containing(deps.as_ref(), "A", None), Vec::<u64>::new());
+        assert_eq!(containing(deps.as_ref(), "D", None), vec![1]);
+    }
+
+    #[test]
+    fn test_query_templates_sorted() {
+        let mut env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        // Ids don't follow creation time
+        for seconds in [200, 100, 300, 200].iter() {
+            env.block.time = Timestamp::from_seconds(*seconds);
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: vec![TierlistItem {
+                    name: "A".to_string(),
+                    image_url: None,
+                    order: 0,
+                    alt_names: None,
+                }],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+        }
+
+        let templates = |deps: Deps, sort, start_after| {
+            let msg = QueryMsg::Templates {
+                start_after,
+                limit: Some(2),
+                open_only: None,
+                category: None,
+                language: None,
+                include_unspecified: None,
+                sort,
+            };
+            query(deps, mock_env(), msg).map(|bin| {
+                let res: Vec<(u64, TierlistTemplate)> = from_binary(&bin).unwrap();
+                res.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(templates(deps.as_ref(), None, None).unwrap(), vec![0, 1]);
+        let sort = Some(TemplateSort::Id);
+        let page = templates(deps.as_ref(), sort, Some(TemplateCursor::Id(1))).unwrap();
+        assert_eq!(page, vec![2, 3]);
+
+        let sort = Some(TemplateSort::Newest);
+        assert_eq!(templates(deps.as_ref(), sort, None).unwrap(), vec![2, 3]);
+        let cursor = Some(TemplateCursor::Newest(200, 3));
+        assert_eq!(templates(deps.as_ref(), sort, cursor).unwrap(), vec![0, 1]);
+        let cursor = Some(TemplateCursor::Newest(100, 1));
+        assert_eq!(
+            templates(deps.as_ref(), sort, cursor).unwrap(),
+            Vec::<u64>::new()
+        );
+
+        // Cursors only continue the sort they came from
+        let err = templates(deps.as_ref(), sort, Some(TemplateCursor::Id(1))).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Cursor does not match the newest sort")
+        );
+        let cursor = Some(TemplateCursor::Newest(200, 3));
+        assert!(templates(deps.as_ref(), None, cursor).is_err());
+    }
+
+    #[test]
+    fn test_create_template_with_collaborators() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let items = vec![TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }];
+
+        let create = |collaborators: Vec<&str>| ExecuteMsg::CreateTemplateWithCollaborators {
+            title: "Tierlist 1".to_string(),
+            items: items.clone(),
+            collaborators: collaborators.into_iter().map(String::from).collect(),
+            visibility: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            create(vec![ADDR3; 6]),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TooManyCollaborators { max: 5 }
+        ));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            create(vec![ADDR3]),
+        )
+        .unwrap();
+        let attr = |key: &str| {
+            let attr = res.attributes.iter().find(|a| a.key == key).unwrap();
+            attr.value.clone()
+        };
+        assert_eq!(attr("template_id"), "0");
+        assert_eq!(attr("collaborators"), "1");
+
+        // The creator and collaborator may edit, anyone else may not
+        let edit = |title: &str| ExecuteMsg::EditTemplate {
+            id: 0,
+            title: title.to_string(),
+            items: items.clone(),
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        for (sender, title) in [(ADDR2, "By creator"), (ADDR3, "By collaborator")] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(sender, &[]),
+                edit(title),
+            )
+            .unwrap();
+            let template = TIERLIST_TEMPLATES.load(&deps.storage, 0).unwrap();
+            assert_eq!(template.title, title);
+            assert_eq!(template.creator, Addr::unchecked(ADDR2));
+        }
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stranger", &[]),
+            edit("By stranger"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // Deleting stays with the creator and admin
+        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        let collaborating = |deps: Deps| -> Vec<u64> {
+            let msg = QueryMsg::CollaboratingTemplates {
+                address: ADDR3.to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect()
+        };
+        assert_eq!(collaborating(deps.as_ref()), vec![0]);
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap();
+        assert!(!TEMPLATE_COLLABORATORS.has(&deps.storage, (0, &Addr::unchecked(ADDR3))));
+        assert_eq!(collaborating(deps.as_ref()), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_weighted_consensus() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let make_item = |name: &str| TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![make_item("A"), make_item("B"), make_item("C")],
+            tier_definitions: Some(vec!["S", "A", "B"].into_iter().map(TierDef::from).collect()),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        // S scores 3, A scores 2 and B scores 1
+        for (addr, tiers) in [
+            (ADDR1, ["B", "A", "S"]),
+            (ADDR2, ["A", "B", "S"]),
+            (ADDR3, ["B", "S", "S"]),
+        ] {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: ["C", "B", "A"]
+                        .iter()
+                        .zip(tiers.iter())
+                        .map(|(name, tier)| (make_item(name), tier.to_string()))
+                        .collect(),
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(addr, &[]), msg).unwrap();
+        }
+
+        let consensus = |deps: Deps, start_after: Option<&str>, limit| {
+            let msg = QueryMsg::WeightedConsensus {
+                template_id: 0,
+                start_after: start_after.map(String::from),
+                limit,
+            };
+            let res: WeightedConsensusResponse =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res
+        };
+        let res = consensus(deps.as_ref(), None, None);
+        assert_eq!(res.submissions, 3);
+        assert_eq!(res.last_address, Some(Addr::unchecked(ADDR3)));
+        let ranked: Vec<_> = res.items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(ranked, vec!["A", "B", "C"]);
+        assert_eq!(res.items[0].average_score, Decimal::percent(300));
+        assert_eq!(res.items[2].average_score, Decimal::from_ratio(4u64, 3u64));
+        assert_eq!(res.items[2].total_score, 4);
+        assert_eq!(res.items[2].placements, 3);
+
+        // Pages over submitters, each ranked on its own
+        let first = consensus(deps.as_ref(), None, Some(2));
+        assert_eq!(first.submissions, 2);
+        assert_eq!(first.last_address, Some(Addr::unchecked(ADDR2)));
+        let ranked: Vec<_> = first.items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(ranked, vec!["A", "B", "C"]);
+        assert_eq!(first.items[1].average_score, Decimal::percent(150));
+        let second = consensus(deps.as_ref(), Some(ADDR2), Some(2));
+        assert_eq!(second.submissions, 1);
+        assert_eq!(second.last_address, Some(Addr::unchecked(ADDR3)));
+        let totals: Vec<_> = first
+            .items
+            .iter()
+            .zip(second.items.iter())
+            .map(|(a, b)| (a.name.as_str(), a.total_score + b.total_score))
+            .collect();
+        assert_eq!(totals, vec![("A", 9), ("B", 6), ("C", 4)]);
+        let res = consensus(deps.as_ref(), Some(ADDR3), None);
+        assert!(res.items.is_empty());
+        assert_eq!(res.last_address, None);
+    }
+
+    #[test]
+    fn test_save_tierlist_first_submission() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let make_item = |name: &str| TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![make_item("A"), make_item("B")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let save = |deps: DepsMut, tiers: [&str; 2]| {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![
+                        (make_item("A"), tiers[0].to_string()),
+                        (make_item("B"), tiers[1].to_string()),
+                    ],
+                },
+                replace: Some(true),
+                idempotency_key: None,
+                force: None,
+            };
+            let res = execute(deps, mock_env(), mock_info(ADDR2, &[]), msg).unwrap();
+            [
+                "first_submission",
+                "previous_assigned_count",
+                "new_assigned_count",
+            ]
+            .map(|key| {
+                let attr = res.attributes.iter().find(|a| a.key == key).unwrap();
+                attr.value.clone()
+            })
+        };
+        assert_eq!(save(deps.as_mut(), ["S", ""]), ["true", "0", "1"]);
+        assert_eq!(save(deps.as_mut(), ["S", "A"]), ["false", "1", "2"]);
+
+        // Overwrites leave the participant count alone
+        let msg = QueryMsg::ParticipantCount { template_id: 0 };
+        let res: ParticipantCountResponse =
+            from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        assert_eq!(res.count, 1);
+    }
+
+    #[test]
+    fn test_save_tierlist_idempotency_key() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let save = |deps: DepsMut, tier: &str, key: String| {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(item.clone(), tier.to_string())],
+                },
+                replace: None,
+                idempotency_key: Some(key),
+                force: None,
+            };
+            execute(deps, mock_env(), mock_info(ADDR2, &[]), msg)
+        };
+        let duplicate = |res: Response| {
+            let attr = res
+                .attributes
+                .iter()
+                .find(|a| a.key == "duplicate")
+                .unwrap();
+            attr.value.clone()
+        };
+        let tier = |deps: Deps| {
+            let tierlist = TIERLISTS
+                .load(deps.storage, (&Addr::unchecked(ADDR2), 0))
+                .unwrap();
+            (
+                tierlist.items_to_tiers[0].1.clone(),
+                tierlist.revisions.len(),
+            )
+        };
+
+        let res = save(deps.as_mut(), "S", "retry-1".to_string()).unwrap();
+        assert_eq!(duplicate(res), "false");
+        // A retry with the same key writes nothing, even if the payload changed
+        let res = save(deps.as_mut(), "A", "retry-1".to_string()).unwrap();
+        assert_eq!(duplicate(res), "true");
+        assert_eq!(tier(deps.as_ref()), ("S".to_string(), 1));
+
+        let res = save(deps.as_mut(), "A", "retry-2".to_string()).unwrap();
+        assert_eq!(duplicate(res), "false");
+        assert_eq!(tier(deps.as_ref()), ("A".to_string(), 2));
+
+        let err = save(deps.as_mut(), "A", "k".repeat(65)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::IdempotencyKeyTooLong { max: 64 }
+        ));
+    }
+
+    #[test]
+    fn test_save_tierlist_unchanged() {
+        let mut env = mock_env();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR1, &[]),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR1, &[]), msg).unwrap();
+
+        let save = |deps: DepsMut, env: Env, tier: &str, force| {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(item.clone(), tier.to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force,
+            };
+            let res = execute(deps, env, mock_info(ADDR2, &[]), msg).unwrap();
+            let attr = res.attributes.iter().find(|a| a.key == "changed").unwrap();
+            attr.value.clone()
+        };
+        let revisions = |deps: Deps| {
+            let tierlist = TIERLISTS
+                .load(deps.storage, (&Addr::unchecked(ADDR2), 0))
+                .unwrap();
+            tierlist.revisions
+        };
+
+        assert_eq!(save(deps.as_mut(), env.clone(), "S", None), "true");
+        let saved = revisions(deps.as_ref());
+
+        // Identical re-saves leave the tierlist and its revisions alone
+        env.block.time = env.block.time.plus_seconds(5);
+        assert_eq!(save(deps.as_mut(), env.clone(), "S", None), "false");
+        assert_eq!(revisions(deps.as_ref()), saved);
+
+        // Forcing records a new revision with the new time
+        assert_eq!(save(deps.as_mut(), env.clone(), "S", Some(true)), "true");
+        let forced = revisions(deps.as_ref());
+        assert_eq!(forced.len(), 2);
+        assert_eq!(forced[1].time, env.block.time);
+
+        assert_eq!(save(deps.as_mut(), env, "A", None), "true");
+        assert_eq!(revisions(deps.as_ref()).len(), 3);
+    }
+
+    #[test]
+    fn test_strict_uniqueness() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+        let item = |name: &str, image: &str| TierlistItem {
+            name: name.to_string(),
+            image_url: Some(image.to_string()),
+            order: 0,
+            alt_names: None,
+        };
+        let alternates = vec![item("A", "a1.png"), item("A", "a2.png")];
+        let create = |items: Vec<TierlistItem>, strict_uniqueness| ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness,
+            visibility: None,
+        };
+
+        // Names are unique by default
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(alternates.clone(), None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateItem { name } if name == "A"));
+
+        // Strict uniqueness allows alternate images but not exact copies
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(alternates.clone(), Some(true)),
+        )
+        .unwrap();
+        assert!(
+            TIERLIST_TEMPLATES
+                .load(&deps.storage, 0)
+                .unwrap()
+                .strict_uniqueness
+        );
+        let copies = vec![item("A", "a1.png"), item("A", "a1.png")];
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(copies.clone(), Some(true)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateItem { name } if name == "A"));
+
+        // Edits keep checking with the template's flag
+        let edit = |items| ExecuteMsg::EditTemplate {
+            id: 0,
+            title: "Tierlist 1".to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), edit(copies)).unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateItem { .. }));
+        let mut items = alternates;
+        items.push(item("A", "a3.png"));
+        execute(deps.as_mut(), env, info, edit(items)).unwrap();
+    }
+
+    #[test]
+    fn test_default_tiers() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = |name: &str| TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let set_defaults = |deps: DepsMut, sender: &str, tiers: Vec<&str>| {
+            let msg = ExecuteMsg::SetDefaultTiers {
+                tiers: tiers.into_iter().map(String::from).collect(),
+            };
+            execute(deps, mock_env(), mock_info(sender, &[]), msg)
+        };
+        let save = |deps: DepsMut, template_id: u64, tier: &str| {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![(item("A"), tier.to_string())],
+                },
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps, mock_env(), mock_info(ADDR2, &[]), msg)
+        };
+        let query_defaults = |deps: Deps| -> Vec<String> {
+            from_binary(&query(deps, mock_env(), QueryMsg::DefaultTiers {}).unwrap()).unwrap()
+        };
+
+        // Template 0 has no tiers of its own, template 1 declares S and A
+        for tier_definitions in [None, Some(vec!["S".into(), "A".into()])].iter() {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: vec![item("A")],
+                tier_definitions: tier_definitions.clone(),
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+
+        // Without defaults any label is accepted
+        assert!(query_defaults(deps.as_ref()).is_empty());
+        save(deps.as_mut(), 0, "Z").unwrap();
+
+        // Admin only and bounded
+        let err = set_defaults(deps.as_mut(), ADDR2, vec!["S"]).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        let err = set_defaults(deps.as_mut(), ADDR1, vec!["T"; 11]).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidDefaultTiers { .. }));
+        let long = "T".repeat(21);
+        let err = set_defaults(deps.as_mut(), ADDR1, vec![long.as_str()]).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidDefaultTiers { .. }));
+        let err = set_defaults(deps.as_mut(), ADDR1, vec!["S", "S"]).unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateTier { .. }));
+
+        set_defaults(deps.as_mut(), ADDR1, vec!["S", "B", "F"]).unwrap();
+        assert_eq!(query_defaults(deps.as_ref()), vec!["S", "B", "F"]);
+
+        // Templates without tiers fall back to the defaults
+        let err = save(deps.as_mut(), 0, "A").unwrap_err();
+        match err {
+            ContractError::InvalidTierLabel { label, template_id } => {
+                assert_eq!(label, "A");
+                assert_eq!(template_id, 0);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+        save(deps.as_mut(), 0, "F").unwrap();
+        save(deps.as_mut(), 0, "").unwrap();
+
+        // A template's own tiers take precedence over the defaults
+        save(deps.as_mut(), 1, "A").unwrap();
+        save(deps.as_mut(), 1, "F").unwrap_err();
+
+        // Clearing the defaults accepts any label again
+        set_defaults(deps.as_mut(), ADDR1, vec![]).unwrap();
+        assert!(query_defaults(deps.as_ref()).is_empty());
+        save(deps.as_mut(), 0, "A").unwrap();
+    }
+
+    #[test]
+    fn test_min_items_per_template() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = |name: &str| TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let create = |items: Vec<TierlistItem>| ExecuteMsg::CreateTemplate {
+            title: "Tierlist".to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        let set_min = |min: u32| ExecuteMsg::UpdateConfig {
+            require_template_tiers: None,
+            hide_creators: None,
+            max_tierlist_bytes: None,
+            min_items_per_template: Some(min),
+        };
+
+        // No minimum by default
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(vec![item("A")]),
+        )
+        .unwrap();
+
+        execute(deps.as_mut(), env.clone(), info.clone(), set_min(2)).unwrap();
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap();
+        let config: ConfigResponse = from_binary(&bin).unwrap();
+        assert_eq!(config.min_items_per_template, Some(2));
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(vec![item("A")]),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TooFewItems { min: 2 }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            create(vec![item("A"), item("B")]),
+        )
+        .unwrap();
+
+        // Edits may not shrink a template below the minimum either
+        let patch = ExecuteMsg::PatchTemplate {
+            id: 1,
+            title: None,
+            items: Some(vec![item("A")]),
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), patch).unwrap_err();
+        assert!(matches!(err, ContractError::TooFewItems { min: 2 }));
+
+        // Zero removes the minimum
+        execute(deps.as_mut(), env.clone(), info.clone(), set_min(0)).unwrap();
+        execute(deps.as_mut(), env, info, create(vec![item("A")])).unwrap();
+    }
+
+    #[test]
+    fn test_remove_tier_definition() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
             },
         )
         .unwrap();
 
-        let bin = query(deps.as_ref(), env, QueryMsg::Config {}).unwrap();
-        let config: Config = from_binary(&bin).unwrap();
-        assert_eq!(
-            config,
-            Config {
-                admin_address: ADDR1.to_string()
-            }
-        );
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: Some(vec!["S".into(), "A".into(), "B".into()]),
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let save = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item, "B".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: Some(true),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            save.clone(),
+        )
+        .unwrap();
+
+        let remove = |tier_name: &str| ExecuteMsg::RemoveTierDefinition {
+            template_id: 0,
+            tier_name: tier_name.to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR3, &[]),
+            remove("B"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            remove("F"),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::TierDefinitionNotFound { tier_name } => assert_eq!(tier_name, "F"),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        // The admin may remove tiers too
+        let res = execute(deps.as_mut(), env.clone(), info, remove("B")).unwrap();
+        let attr = res
+            .attributes
+            .iter()
+            .find(|a| a.key == "tier_name")
+            .unwrap();
+        assert_eq!(attr.value, "B");
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Template {
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        let template = from_binary::<TemplateResponse>(&bin)
+            .unwrap()
+            .template
+            .unwrap();
+        let names: Vec<_> = template
+            .tier_definitions
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["S", "A"]);
+
+        // The saved tierlist is kept but can no longer be saved with the removed tier
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Tierlist {
+                address: ADDR2.to_string(),
+                id: 0,
+                strict: None,
+            },
+        )
+        .unwrap();
+        assert!(from_binary::<TierlistResponse>(&bin)
+            .unwrap()
+            .tierlist
+            .is_some());
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), save).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTierLabel { .. }));
+
+        // Two tiers must remain
+        let err = execute(deps.as_mut(), env, mock_info(ADDR2, &[]), remove("A")).unwrap_err();
+        assert!(matches!(err, ContractError::TooFewTiers { min: 2 }));
     }
 
     #[test]
-    fn test_create_template() {
+    fn test_tierlist_ordered() {
         let env = mock_env();
         let mut deps = mock_dependencies();
         let info = mock_info(ADDR1, &[]);
@@ -255,335 +11655,802 @@ mod tests {
             info.clone(),
             InstantiateMsg {
                 admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
             },
         )
         .unwrap();
 
+        let item = |name: &str, order: u32| TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order,
+            alt_names: None,
+        };
+        // Display order is B, C, A
         let msg = ExecuteMsg::CreateTemplate {
             title: "Tierlist 1".to_string(),
-            items: vec![
-                TierlistItem {
+            items: vec![item("A", 2), item("B", 0), item("C", 1)],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 0,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![
+                    (item("C", 1), "A".to_string()),
+                    (item("A", 2), "S".to_string()),
+                    (item("B", 0), "B".to_string()),
+                ],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let query_ordered = |deps: Deps, address: &str| {
+            let msg = QueryMsg::TierlistOrdered {
+                address: address.to_string(),
+                id: 0,
+            };
+            from_binary::<TierlistResponse>(&query(deps, mock_env(), msg).unwrap())
+                .unwrap()
+                .tierlist
+        };
+        let tierlist = query_ordered(deps.as_ref(), ADDR1).unwrap();
+        let order: Vec<_> = tierlist
+            .items_to_tiers
+            .iter()
+            .map(|(item, tier)| (item.name.as_str(), tier.as_str()))
+            .collect();
+        assert_eq!(order, vec![("B", "B"), ("C", "A"), ("A", "S")]);
+
+        // The stored tierlist keeps its submitted order
+        let stored = TIERLISTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR1), 0))
+            .unwrap();
+        assert_eq!(stored.items_to_tiers[0].0.name, "C");
+        assert_eq!(query_ordered(deps.as_ref(), ADDR2), None);
+    }
+
+    #[test]
+    fn test_save_tierlists() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        for tier_definitions in [None, None, Some(vec!["S".into(), "A".into()])].iter() {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: vec![item.clone()],
+                tier_definitions: tier_definitions.clone(),
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        }
+        let tierlist = |template_id: u64, tier: &str| Tierlist {
+            template_id,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![(item.clone(), tier.to_string())],
+        };
+        let has_tierlist = |deps: Deps, template_id: u64| {
+            TIERLISTS.has(deps.storage, (&Addr::unchecked(ADDR2), template_id))
+        };
+
+        // Two valid tierlists are saved together
+        let msg = ExecuteMsg::SaveTierlists {
+            tierlists: vec![tierlist(0, "S"), tierlist(1, "A")],
+            best_effort: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let attr = res.attributes.iter().find(|a| a.key == "saved").unwrap();
+        assert_eq!(attr.value, "0,1");
+        assert!(has_tierlist(deps.as_ref(), 0));
+        assert!(has_tierlist(deps.as_ref(), 1));
+
+        // One invalid tierlist fails the whole batch
+        let batch = vec![tierlist(0, "B"), tierlist(2, "F"), tierlist(7, "S")];
+        let msg = ExecuteMsg::SaveTierlists {
+            tierlists: batch.clone(),
+            best_effort: Some(false),
+        };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTierLabel { .. }));
+
+        // Best effort saves the valid ones and skips the rest
+        let msg = ExecuteMsg::SaveTierlists {
+            tierlists: batch,
+            best_effort: Some(true),
+        };
+        let res = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let attr = res.attributes.iter().find(|a| a.key == "saved").unwrap();
+        assert_eq!(attr.value, "0");
+        let attr = res.attributes.iter().find(|a| a.key == "skipped").unwrap();
+        assert_eq!(attr.value, "2,7");
+        let saved = TIERLISTS
+            .load(&deps.storage, (&Addr::unchecked(ADDR2), 0))
+            .unwrap();
+        assert_eq!(saved.items_to_tiers[0].1, "B");
+        assert!(!has_tierlist(deps.as_ref(), 2));
+
+        // Batches are capped and refuse funds
+        let msg = ExecuteMsg::SaveTierlists {
+            tierlists: vec![tierlist(0, "S"); 11],
+            best_effort: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::TooManyTierlists { max: 10 }));
+        let msg = ExecuteMsg::SaveTierlists {
+            tierlists: vec![tierlist(0, "S")],
+            best_effort: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info(ADDR2, &[coin(5, "ujuno")]),
+            msg,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::BatchFundsNotAccepted {}));
+    }
+
+    #[test]
+    fn test_add_tier_definition() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        // Template 0 has no tiers, template 1 declares S and A
+        for tier_definitions in [None, Some(vec!["S".into(), "A".into()])].iter() {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: vec![TierlistItem {
                     name: "A".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
+                }],
+                tier_definitions: tier_definitions.clone(),
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: None,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        }
+        let add = |deps: DepsMut, sender: &str, template_id: u64, tier_name: &str| {
+            let msg = ExecuteMsg::AddTierDefinition {
+                template_id,
+                tier_name: tier_name.to_string(),
+            };
+            execute(deps, mock_env(), mock_info(sender, &[]), msg)
+        };
+        let tier_names = |deps: Deps, template_id: u64| -> Vec<String> {
+            TIERLIST_TEMPLATES
+                .load(deps.storage, template_id)
+                .unwrap()
+                .tier_definitions
+                .unwrap()
+                .into_iter()
+                .map(|t| t.name)
+                .collect()
+        };
+
+        let err = add(deps.as_mut(), ADDR3, 0, "S").unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // Adding to a template without tiers defines them
+        add(deps.as_mut(), ADDR2, 0, "S").unwrap();
+        assert_eq!(tier_names(deps.as_ref(), 0), vec!["S"]);
+
+        // Adding to existing tiers appends, the admin may add too
+        add(deps.as_mut(), ADDR1, 1, "B").unwrap();
+        assert_eq!(tier_names(deps.as_ref(), 1), vec!["S", "A", "B"]);
+        let err = add(deps.as_mut(), ADDR2, 1, "A").unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateTier { .. }));
+        let err = add(deps.as_mut(), ADDR2, 1, "").unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTierLabel { .. }));
+
+        // Tiers are capped
+        for name in ["C", "D", "E", "F", "G", "H", "I"].iter() {
+            add(deps.as_mut(), ADDR2, 1, name).unwrap();
+        }
+        assert_eq!(tier_names(deps.as_ref(), 1).len(), 10);
+        let err = add(deps.as_mut(), ADDR2, 1, "J").unwrap_err();
+        assert!(matches!(err, ContractError::TooManyTiers { max: 10 }));
+    }
+
+    #[test]
+    fn test_template_ids_not_reused() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let create = ExecuteMsg::CreateTemplate {
+            title: "Tierlist".to_string(),
+            items: vec![TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            }],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        let next_id = |deps: Deps| -> u64 {
+            from_binary(&query(deps, mock_env(), QueryMsg::NextTemplateId {}).unwrap()).unwrap()
+        };
+        let template = |deps: Deps, id: u64| {
+            let msg = QueryMsg::Template { id, strict: None };
+            from_binary::<TemplateResponse>(&query(deps, mock_env(), msg).unwrap())
+                .unwrap()
+                .template
+        };
+
+        assert_eq!(next_id(deps.as_ref()), 0);
+        for _ in 0..8 {
+            execute(deps.as_mut(), env.clone(), info.clone(), create.clone()).unwrap();
+        }
+        assert_eq!(next_id(deps.as_ref()), 8);
+
+        // Deleting the newest template leaves the counter alone
+        let msg = ExecuteMsg::DeleteTemplate { id: 7 };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert_eq!(next_id(deps.as_ref()), 8);
+
+        execute(deps.as_mut(), env, info, create).unwrap();
+        assert_eq!(next_id(deps.as_ref()), 9);
+        assert!(template(deps.as_ref(), 8).is_some());
+        assert_eq!(template(deps.as_ref(), 7), None);
+    }
+
+    #[test]
+    fn test_tierlist_agreement_score() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = |name: &str| TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        let msg = ExecuteMsg::CreateTemplate {
+            title: "Tierlist 1".to_string(),
+            items: vec![item("A"), item("B"), item("C")],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let save = |deps: DepsMut, sender: &str, tiers: [&str; 3]| {
+            let msg = ExecuteMsg::SaveTierlist {
+                tierlist: Tierlist {
+                    template_id: 0,
+                    revisions: vec![],
+                    locked: false,
+                    items_to_tiers: vec![
+                        (item("A"), tiers[0].to_string()),
+                        (item("B"), tiers[1].to_string()),
+                        (item("C"), tiers[2].to_string()),
+                    ],
                 },
-                TierlistItem {
-                    name: "B".to_string(),
-                    image_url: None,
-                },
-                TierlistItem {
-                    name: "C".to_string(),
-                    image_url: None,
-                },
-            ],
+                replace: None,
+                idempotency_key: None,
+                force: None,
+            };
+            execute(deps, mock_env(), mock_info(sender, &[]), msg).unwrap();
+        };
+        let score = |deps: Deps, address_b: &str| {
+            let msg = QueryMsg::TierlistAgreementScore {
+                template_id: 0,
+                address_a: ADDR1.to_string(),
+                address_b: address_b.to_string(),
+            };
+            query(deps, mock_env(), msg)
+                .map(|bin| from_binary::<AgreementScoreResponse>(&bin).unwrap())
+        };
+
+        save(deps.as_mut(), ADDR1, ["S", "A", ""]);
+
+        // Identical, including the unassigned item
+        save(deps.as_mut(), ADDR2, ["S", "A", ""]);
+        assert_eq!(
+            score(deps.as_ref(), ADDR2).unwrap(),
+            AgreementScoreResponse {
+                identical_assignments: 3,
+                total_items: 3,
+                score_percentage: 100,
+            }
+        );
+
+        // Completely different
+        save(deps.as_mut(), ADDR2, ["B", "S", "A"]);
+        assert_eq!(
+            score(deps.as_ref(), ADDR2).unwrap(),
+            AgreementScoreResponse {
+                identical_assignments: 0,
+                total_items: 3,
+                score_percentage: 0,
+            }
+        );
+
+        // Partially overlapping, rounded down
+        save(deps.as_mut(), ADDR2, ["S", "B", ""]);
+        assert_eq!(
+            score(deps.as_ref(), ADDR2).unwrap(),
+            AgreementScoreResponse {
+                identical_assignments: 2,
+                total_items: 3,
+                score_percentage: 66,
+            }
+        );
+
+        // Both tierlists must exist
+        score(deps.as_ref(), ADDR3).unwrap_err();
+    }
+
+    #[test]
+    fn test_unlisted_templates() {
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADDR1, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
+            },
+        )
+        .unwrap();
+
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
+        // Template 1 is unlisted
+        for visibility in [None, Some(TemplateVisibility::Unlisted)].iter() {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: vec![item.clone()],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: *visibility,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        }
+        let listed = |deps: Deps, msg: QueryMsg| {
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        let templates = QueryMsg::Templates {
+            start_after: None,
+            limit: None,
+            open_only: None,
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
+        };
+        let search = |creator: Option<&str>, include_unlisted| QueryMsg::SearchTemplates {
+            creator: creator.map(String::from),
+            open_only: None,
+            language: None,
+            include_unspecified: None,
+            include_archived: None,
+            include_unlisted,
+            start_after: None,
+            limit: None,
+        };
+        let search_ids = |deps: Deps, msg: QueryMsg| {
+            let res: SearchTemplatesResponse =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.templates
+                .into_iter()
+                .map(|(id, template)| (id, template.visibility))
+                .collect::<Vec<_>>()
+        };
+
+        // Left out of the public listings
+        assert_eq!(listed(deps.as_ref(), templates.clone()), vec![0]);
+        assert_eq!(
+            search_ids(deps.as_ref(), search(None, Some(true))),
+            vec![(0, TemplateVisibility::Public)]
+        );
+
+        // Still loads by id and accepts saves
+        let msg = QueryMsg::Template {
+            id: 1,
+            strict: Some(true),
+        };
+        let res: TemplateResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(
+            res.template.unwrap().visibility,
+            TemplateVisibility::Unlisted
+        );
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist: Tierlist {
+                template_id: 1,
+                revisions: vec![],
+                locked: false,
+                items_to_tiers: vec![(item.clone(), "S".to_string())],
+            },
+            replace: None,
+            idempotency_key: None,
+            force: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-        let msg = QueryMsg::Template { id: 0 };
-        let bin = query(deps.as_ref(), env, msg).unwrap();
-        let template: TemplateResponse = from_binary(&bin).unwrap();
+        // Submissions to it are left out of the submitter's listing
+        let msg = QueryMsg::TemplatesWithUserTierlist {
+            address: ADDR1.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let res: Vec<TierlistWithTemplate> =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert!(res.is_empty());
+
+        // The creator listing only includes it when asked, flagged by its visibility
         assert_eq!(
-            template.template,
-            Some(TierlistTemplate {
-                id: 0,
-                title: "Tierlist 1".to_string(),
-                items: vec![
-                    TierlistItem {
-                        name: "A".to_string(),
-                        image_url: None,
-                    },
-                    TierlistItem {
-                        name: "B".to_string(),
-                        image_url: None,
-                    },
-                    TierlistItem {
-                        name: "C".to_string(),
-                        image_url: None,
-                    },
-                ],
-                creator: ADDR1.to_string()
-            })
+            search_ids(deps.as_ref(), search(Some(ADDR2), None)),
+            vec![(0, TemplateVisibility::Public)]
+        );
+        assert_eq!(
+            search_ids(deps.as_ref(), search(Some(ADDR2), Some(true))),
+            vec![
+                (0, TemplateVisibility::Public),
+                (1, TemplateVisibility::Unlisted)
+            ]
         );
+
+        // Editing without a visibility keeps it, setting one lists the template again
+        let edit = |visibility| ExecuteMsg::EditTemplate {
+            id: 1,
+            title: "Tierlist".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            visibility,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADDR2, &[]),
+            edit(None),
+        )
+        .unwrap();
+        assert_eq!(listed(deps.as_ref(), templates.clone()), vec![0]);
+        let msg = edit(Some(TemplateVisibility::Public));
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap();
+        assert_eq!(listed(deps.as_ref(), templates), vec![0, 1]);
     }
 
     #[test]
-    fn test_edit_template() {
+    fn test_unlisted_templates_in_listings() {
         let env = mock_env();
         let mut deps = mock_dependencies();
-        let info = mock_info(ADDR1, &[]);
         instantiate(
             deps.as_mut(),
             env.clone(),
-            info.clone(),
+            mock_info(ADDR1, &[]),
             InstantiateMsg {
                 admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
             },
         )
         .unwrap();
+        let item = TierlistItem {
+            name: "A".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        };
 
+        // Template 0 is public, 1 is unlisted with ADDR3 collaborating and 2 is an unlisted fork of 0
         let msg = ExecuteMsg::CreateTemplate {
-            title: "Tierlist 1".to_string(),
-            items: vec![
-                TierlistItem {
-                    name: "A".to_string(),
-                    image_url: None,
-                },
-                TierlistItem {
-                    name: "B".to_string(),
-                    image_url: None,
-                },
-                TierlistItem {
-                    name: "C".to_string(),
-                    image_url: None,
-                },
-            ],
+            title: "Tierlist".to_string(),
+            items: vec![item.clone()],
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
         };
-        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
-
-        let msg = ExecuteMsg::EditTemplate {
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let msg = ExecuteMsg::CreateTemplateWithCollaborators {
+            title: "Tierlist".to_string(),
+            items: vec![item.clone()],
+            collaborators: vec![ADDR3.to_string()],
+            visibility: Some(TemplateVisibility::Unlisted),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        let msg = ExecuteMsg::CloneTemplate {
             id: 0,
-            title: "NewTitle".to_string(),
-            items: vec![
-                TierlistItem {
-                    name: "A".to_string(),
-                    image_url: None,
-                },
-                TierlistItem {
-                    name: "C".to_string(),
-                    image_url: None,
-                },
-            ],
+            visibility: Some(TemplateVisibility::Unlisted),
         };
-        // Try and edit as non admin non owner
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            mock_info(ADDR2, &[]),
-            msg.clone(),
-        )
-        .unwrap_err();
-
-        // Valid edit
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR3, &[]), msg).unwrap();
+        let msg = ExecuteMsg::ArchiveTemplate { id: 1 };
+        execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
 
-        // Query the template
-        let msg = QueryMsg::Template { id: 0 };
-        let bin = query(deps.as_ref(), env, msg).unwrap();
-        let template: TemplateResponse = from_binary(&bin).unwrap();
-        assert_eq!(
-            template.template,
-            Some(TierlistTemplate {
-                id: 0,
-                title: "NewTitle".to_string(),
-                items: vec![
-                    TierlistItem {
-                        name: "A".to_string(),
-                        image_url: None,
-                    },
-                    TierlistItem {
-                        name: "C".to_string(),
-                        image_url: None,
-                    },
-                ],
-                creator: ADDR1.to_string()
-            })
-        )
+        let ids = |msg: QueryMsg| {
+            let res: Vec<(u64, TierlistTemplate)> =
+                from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+            res.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        let msg = QueryMsg::ArchivedTemplates {
+            start_after: None,
+            limit: None,
+        };
+        assert!(ids(msg).is_empty());
+        let msg = QueryMsg::CollaboratingTemplates {
+            address: ADDR3.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        assert!(ids(msg).is_empty());
+        let msg = QueryMsg::TemplateForks { id: 0 };
+        let res: TemplateForksResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert!(res.forks.is_empty());
+        let msg = QueryMsg::TemplatesByItemCount {
+            min_items: None,
+            max_items: None,
+            start_after: None,
+            limit: None,
+        };
+        let res: TemplatesByItemCountResponse =
+            from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        assert_eq!(res.templates.len(), 1);
+        assert_eq!(res.templates[0].0, 0);
+        assert_eq!(res.last_scanned, Some(2));
     }
 
     #[test]
-    fn test_delete_template() {
+    fn test_template_slugs() {
         let env = mock_env();
         let mut deps = mock_dependencies();
-        let info = mock_info(ADDR1, &[]);
         instantiate(
             deps.as_mut(),
             env.clone(),
-            info.clone(),
+            mock_info(ADDR1, &[]),
             InstantiateMsg {
                 admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
             },
         )
         .unwrap();
-
-        let msg = ExecuteMsg::CreateTemplate {
-            title: "Tierlist 1".to_string(),
-            items: vec![
-                TierlistItem {
+        for visibility in [Some(TemplateVisibility::Unlisted), None].iter() {
+            let msg = ExecuteMsg::CreateTemplate {
+                title: "Tierlist".to_string(),
+                items: vec![TierlistItem {
                     name: "A".to_string(),
                     image_url: None,
-                },
-                TierlistItem {
-                    name: "B".to_string(),
-                    image_url: None,
-                },
-                TierlistItem {
-                    name: "C".to_string(),
-                    image_url: None,
-                },
-            ],
+                    order: 0,
+                    alt_names: None,
+                }],
+                tier_definitions: None,
+                max_submissions: None,
+                language: None,
+                strict_uniqueness: None,
+                visibility: *visibility,
+            };
+            execute(deps.as_mut(), env.clone(), mock_info(ADDR2, &[]), msg).unwrap();
+        }
+        let set_slug = |deps: DepsMut, sender: &str, id: u64, slug: Option<&str>| {
+            let msg = ExecuteMsg::SetTemplateSlug {
+                id,
+                slug: slug.map(String::from),
+            };
+            execute(deps, mock_env(), mock_info(sender, &[]), msg)
+        };
+        let by_slug = |deps: Deps, slug: &str| {
+            let msg = QueryMsg::TemplateBySlug {
+                slug: slug.to_string(),
+            };
+            let res: TemplateResponse =
+                from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+            res.template.map(|template| template.id)
         };
-        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
-        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
-        // Try to delete as non admin or creator
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            mock_info(ADDR2, &[]),
-            msg.clone(),
-        )
-        .unwrap_err();
-        // Valid delete
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let err = set_slug(deps.as_mut(), ADDR3, 0, Some("fruit")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        for slug in ["", "Fruit", "fruit salad", &"a".repeat(MAX_SLUG_LENGTH + 1)].iter() {
+            let err = set_slug(deps.as_mut(), ADDR2, 0, Some(slug)).unwrap_err();
+            assert!(matches!(err, ContractError::InvalidSlug { .. }));
+        }
 
-        let msg = QueryMsg::Template { id: 0 };
-        let bin = query(deps.as_ref(), env, msg).unwrap();
-        let res: TemplateResponse = from_binary(&bin).unwrap();
-        assert_eq!(res.template, None);
+        // The slug finds the unlisted template
+        set_slug(deps.as_mut(), ADDR2, 0, Some("fruit-2024")).unwrap();
+        set_slug(deps.as_mut(), ADDR2, 0, Some("fruit-2024")).unwrap();
+        assert_eq!(by_slug(deps.as_ref(), "fruit-2024"), Some(0));
+        assert_eq!(by_slug(deps.as_ref(), "fruit"), None);
+        let err = set_slug(deps.as_mut(), ADDR2, 1, Some("fruit-2024")).unwrap_err();
+        assert!(matches!(err, ContractError::SlugTaken { .. }));
+
+        // Changing or deleting frees the previous slug
+        set_slug(deps.as_mut(), ADDR2, 0, Some("fruit")).unwrap();
+        assert_eq!(by_slug(deps.as_ref(), "fruit-2024"), None);
+        set_slug(deps.as_mut(), ADDR2, 1, Some("fruit-2024")).unwrap();
+        let msg = ExecuteMsg::DeleteTemplate { id: 0 };
+        execute(deps.as_mut(), env, mock_info(ADDR2, &[]), msg).unwrap();
+        assert_eq!(by_slug(deps.as_ref(), "fruit"), None);
+        set_slug(deps.as_mut(), ADDR2, 1, Some("fruit")).unwrap();
+        assert_eq!(by_slug(deps.as_ref(), "fruit"), Some(1));
+        set_slug(deps.as_mut(), ADDR2, 1, None).unwrap();
+        assert_eq!(by_slug(deps.as_ref(), "fruit"), None);
     }
 
     #[test]
-    fn test_save_tierlist() {
+    fn test_external_templates() {
         let env = mock_env();
         let mut deps = mock_dependencies();
-        let info = mock_info(ADDR1, &[]);
         instantiate(
             deps.as_mut(),
             env.clone(),
-            info.clone(),
+            mock_info(ADDR1, &[]),
             InstantiateMsg {
                 admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
             },
         )
         .unwrap();
 
-        let msg = ExecuteMsg::CreateTemplate {
-            title: "Tierlist 1".to_string(),
-            items: vec![
-                TierlistItem {
-                    name: "A".to_string(),
-                    image_url: None,
-                },
-                TierlistItem {
-                    name: "B".to_string(),
-                    image_url: None,
-                },
-                TierlistItem {
-                    name: "C".to_string(),
-                    image_url: None,
-                },
-            ],
+        let register = |deps: DepsMut, external_id: &str, metadata_url: &str| {
+            let msg = ExecuteMsg::RegisterExternalTemplate {
+                external_id: external_id.to_string(),
+                metadata_url: metadata_url.to_string(),
+            };
+            execute(deps, mock_env(), mock_info(ADDR2, &[]), msg)
         };
-        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
-        // Valid tierlist
-        let tierlist = Tierlist {
-            template_id: 0,
-            items_to_tiers: vec![
-                (
-                    TierlistItem {
-                        name: "A".to_string(),
-                        image_url: None,
-                    },
-                    "S".to_string(),
-                ),
-                (
-                    TierlistItem {
-                        name: "B".to_string(),
-                        image_url: None,
-                    },
-                    "A".to_string(),
-                ),
-                (
-                    TierlistItem {
-                        name: "C".to_string(),
-                        image_url: None,
-                    },
-                    "B".to_string(),
-                ),
-            ],
-        };
-        let msg = ExecuteMsg::SaveTierlist {
-            tierlist: tierlist.clone(),
-        };
-        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        register(deps.as_mut(), "b", "ipfs://bafybeigdyrzt").unwrap();
+        register(deps.as_mut(), "a", "https://example.com/a.json").unwrap();
+        register(deps.as_mut(), "c", "ar://abc").unwrap();
 
-        // Query for it
-        let msg = QueryMsg::Tierlist {
-            address: ADDR1.to_string(),
-            id: 0,
-        };
-        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
-        let res: TierlistResponse = from_binary(&bin).unwrap();
-        assert_eq!(res.tierlist, Some(tierlist));
+        // Ids are unique and urls need a known scheme
+        let err = register(deps.as_mut(), "a", "https://example.com/b.json").unwrap_err();
+        assert!(matches!(err, ContractError::ExternalTemplateExists { .. }));
+        for url in [
+            "ftp://example.com",
+            "https://",
+            "example.com",
+            "https://a b",
+        ]
+        .iter()
+        {
+            let err = register(deps.as_mut(), "d", url).unwrap_err();
+            assert!(matches!(err, ContractError::InvalidMetadataUrl { .. }));
+        }
+        let long_url = format!("https://{}", "a".repeat(MAX_METADATA_URL_LENGTH));
+        let err = register(deps.as_mut(), "d", &long_url).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMetadataUrl { .. }));
+        let err = register(deps.as_mut(), "", "ar://abc").unwrap_err();
+        assert!(matches!(err, ContractError::InvalidExternalId { max: 64 }));
 
-        // Query for nonexistent
-        let msg = QueryMsg::Tierlist {
-            address: ADDR2.to_string(),
-            id: 0,
+        let msg = QueryMsg::ExternalTemplate {
+            external_id: "a".to_string(),
         };
-        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
-        let res: TierlistResponse = from_binary(&bin).unwrap();
-        assert_eq!(res.tierlist, None);
-
-        // Invalid tierlist, missing options
-        let tierlist = Tierlist {
-            template_id: 0,
-            items_to_tiers: vec![
-                (
-                    TierlistItem {
-                        name: "A".to_string(),
-                        image_url: None,
-                    },
-                    "S".to_string(),
-                ),
-                (
-                    TierlistItem {
-                        name: "B".to_string(),
-                        image_url: None,
-                    },
-                    "A".to_string(),
-                ),
-            ],
+        let res: ExternalTemplateResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(
+            res.template,
+            Some(ExternalTemplateRef {
+                external_id: "a".to_string(),
+                metadata_url: "https://example.com/a.json".to_string(),
+                registered_by: Addr::unchecked(ADDR2),
+                created_at: env.block.time,
+            })
+        );
+        let msg = QueryMsg::ExternalTemplate {
+            external_id: "d".to_string(),
         };
-        let msg = ExecuteMsg::SaveTierlist { tierlist };
-        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        let res: ExternalTemplateResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(res.template, None);
 
-        // Invalid tierlist, additional options
-        let tierlist = Tierlist {
-            template_id: 0,
-            items_to_tiers: vec![
-                (
-                    TierlistItem {
-                        name: "A".to_string(),
-                        image_url: None,
-                    },
-                    "S".to_string(),
-                ),
-                (
-                    TierlistItem {
-                        name: "B".to_string(),
-                        image_url: None,
-                    },
-                    "A".to_string(),
-                ),
-                (
-                    TierlistItem {
-                        name: "C".to_string(),
-                        image_url: None,
-                    },
-                    "A".to_string(),
-                ),
-                (
-                    TierlistItem {
-                        name: "D".to_string(),
-                        image_url: None,
-                    },
-                    "A".to_string(),
-                ),
-            ],
+        // Listed by external id
+        let list = |start_after: Option<&str>| {
+            let msg = QueryMsg::ExternalTemplates {
+                start_after: start_after.map(String::from),
+                limit: Some(2),
+            };
+            let res: Vec<ExternalTemplateRef> =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            res.into_iter().map(|t| t.external_id).collect::<Vec<_>>()
         };
-        let msg = ExecuteMsg::SaveTierlist { tierlist };
-        execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert_eq!(list(None), vec!["a", "b"]);
+        assert_eq!(list(Some("b")), vec!["c"]);
     }
 
     #[test]
@@ -597,94 +12464,165 @@ mod tests {
             info.clone(),
             InstantiateMsg {
                 admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
             },
         )
         .unwrap();
 
         let msg = ExecuteMsg::CreateTemplate {
             title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
             items: vec![
                 TierlistItem {
                     name: "A".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "B".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "C".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
             ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
         let msg = ExecuteMsg::CreateTemplate {
             title: "Tierlist 2".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
             items: vec![
                 TierlistItem {
                     name: "D".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "E".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "F".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
             ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
         let tierlist_1 = Tierlist::from_template(TierlistTemplate {
             id: 0,
             title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
             items: vec![
                 TierlistItem {
                     name: "A".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "B".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "C".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
             ],
-            creator: ADDR1.to_string(),
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
         });
 
         let tierlist_2 = Tierlist::from_template(TierlistTemplate {
             id: 1,
             title: "Tierlist 2".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
             items: vec![
                 TierlistItem {
                     name: "D".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "E".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "F".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
             ],
-            creator: ADDR1.to_string(),
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
         });
 
         let msg = ExecuteMsg::SaveTierlist {
             tierlist: tierlist_1,
+            replace: None,
+            idempotency_key: None,
+            force: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
         let msg = ExecuteMsg::SaveTierlist {
             tierlist: tierlist_2,
+            replace: None,
+            idempotency_key: None,
+            force: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -718,102 +12656,173 @@ mod tests {
             info.clone(),
             InstantiateMsg {
                 admin_address: ADDR1.to_string(),
+                require_template_tiers: false,
+                hide_creators: false,
+                max_tierlist_bytes: None,
             },
         )
         .unwrap();
 
         let msg = ExecuteMsg::CreateTemplate {
             title: "Tierlist 1".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
             items: vec![
                 TierlistItem {
                     name: "A".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "B".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "C".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
             ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
         let msg = ExecuteMsg::CreateTemplate {
             title: "Tierlist 2".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
             items: vec![
                 TierlistItem {
                     name: "D".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "E".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 TierlistItem {
                     name: "F".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
             ],
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
         let msg = QueryMsg::Templates {
             start_after: None,
             limit: None,
+            open_only: None,
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
         };
-        let bin = query(deps.as_ref(), env, msg).unwrap();
+        let bin = query(deps.as_ref(), env.clone(), msg).unwrap();
         let res: Vec<(u64, TierlistTemplate)> = from_binary(&bin).unwrap();
-        assert_eq!(
-            res,
-            vec![
-                (
-                    0,
-                    TierlistTemplate {
-                        id: 0,
-                        title: "Tierlist 1".to_string(),
-                        items: vec![
-                            TierlistItem {
-                                name: "A".to_string(),
-                                image_url: None
-                            },
-                            TierlistItem {
-                                name: "B".to_string(),
-                                image_url: None
-                            },
-                            TierlistItem {
-                                name: "C".to_string(),
-                                image_url: None
-                            }
-                        ],
-                        creator: ADDR1.to_string()
-                    }
-                ),
-                (
-                    1,
-                    TierlistTemplate {
-                        id: 1,
-                        title: "Tierlist 2".to_string(),
-                        items: vec![
-                            TierlistItem {
-                                name: "D".to_string(),
-                                image_url: None
-                            },
-                            TierlistItem {
-                                name: "E".to_string(),
-                                image_url: None
-                            },
-                            TierlistItem {
-                                name: "F".to_string(),
-                                image_url: None
-                            }
-                        ],
-                        creator: ADDR1.to_string()
-                    }
-                ),
-            ]
-        );
+        let mut expected = vec![
+            (
+                0,
+                TierlistTemplate {
+                    id: 0,
+                    title: "Tierlist 1".to_string(),
+                    tier_definitions: None,
+                    max_submissions: None,
+                    items: vec![
+                        TierlistItem {
+                            name: "A".to_string(),
+                            image_url: None,
+                            order: 0,
+                            alt_names: None,
+                        },
+                        TierlistItem {
+                            name: "B".to_string(),
+                            image_url: None,
+                            order: 0,
+                            alt_names: None,
+                        },
+                        TierlistItem {
+                            name: "C".to_string(),
+                            image_url: None,
+                            order: 0,
+                            alt_names: None,
+                        },
+                    ],
+                    creator: Addr::unchecked(ADDR1),
+                    submissions_open: true,
+                    sealed_at: None,
+                    expires_at: None,
+                    category: None,
+                    language: None,
+                    archived: false,
+                    forked_from: None,
+                    source: None,
+                    created_at: Some(env.block.time),
+                    strict_uniqueness: false,
+                    visibility: TemplateVisibility::Public,
+                    content_hash: Binary::default(),
+                },
+            ),
+            (
+                1,
+                TierlistTemplate {
+                    id: 1,
+                    title: "Tierlist 2".to_string(),
+                    tier_definitions: None,
+                    max_submissions: None,
+                    items: vec![
+                        TierlistItem {
+                            name: "D".to_string(),
+                            image_url: None,
+                            order: 0,
+                            alt_names: None,
+                        },
+                        TierlistItem {
+                            name: "E".to_string(),
+                            image_url: None,
+                            order: 0,
+                            alt_names: None,
+                        },
+                        TierlistItem {
+                            name: "F".to_string(),
+                            image_url: None,
+                            order: 0,
+                            alt_names: None,
+                        },
+                    ],
+                    creator: Addr::unchecked(ADDR1),
+                    submissions_open: true,
+                    sealed_at: None,
+                    expires_at: None,
+                    category: None,
+                    language: None,
+                    archived: false,
+                    forked_from: None,
+                    source: None,
+                    created_at: Some(env.block.time),
+                    strict_uniqueness: false,
+                    visibility: TemplateVisibility::Public,
+                    content_hash: Binary::default(),
+                },
+            ),
+        ];
+        for (_, template) in expected.iter_mut() {
+            template.refresh_content_hash();
+        }
+        assert_eq!(res, expected);
     }
 }
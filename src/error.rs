@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,9 +6,675 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
-    #[error("Unauthorized")]
-    Unauthorized {},
+    #[error("Unauthorized: {reason}")]
+    Unauthorized { reason: String },
 
-    #[error("Invalid tierlist, the tierlist does not match the specified template")]
-    InvalidTierlist {},
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("Invalid tierlist, {0}")]
+    InvalidTierlist(#[from] TierlistValidationError),
+
+    #[error("Invalid tier label '{label}', it is not defined by template {template_id}")]
+    InvalidTierLabel { label: String, template_id: u64 },
+
+    #[error("Item '{name}' does not exist in this template")]
+    ItemNotFound { name: String },
+
+    #[error("Templates must have at least one item")]
+    NoItems {},
+
+    #[error("Templates may have at most {max} items")]
+    TooManyItems { max: usize },
+
+    #[error("Templates must have at least {min} items")]
+    TooFewItems { min: u32 },
+
+    #[error("Item '{name}' is listed more than once")]
+    DuplicateItem { name: String },
+
+    #[error("Items must not share the same order, {order} is used more than once")]
+    DuplicateItemOrder { order: u32 },
+
+    #[error("At most {max} tierlists may be saved at once")]
+    TooManyTierlists { max: usize },
+
+    #[error("Batch saves do not accept funds, save templates with fees one at a time")]
+    BatchFundsNotAccepted {},
+
+    #[error("Insufficient fee, {required} required but {provided} provided")]
+    InsufficientFee {
+        required: Uint128,
+        provided: Uint128,
+    },
+
+    #[error("Template fees need a non-zero amount and a denom")]
+    InvalidTemplateFee {},
+
+    #[error("Template takes no fee, funds are not accepted")]
+    FundsNotAccepted {},
+
+    #[error("Received unsupported denom '{denom}'")]
+    UnexpectedDenom { denom: String },
+
+    #[error("Template {id} does not exist")]
+    TemplateNotFound { id: u64 }, // The id was assigned but the template has been deleted
+
+    #[error("Template id {id} has never been assigned")]
+    InvalidTemplateId { id: u64 },
+
+    #[error("Address is banned")]
+    AddressBanned {},
+
+    #[error("{address} has not submitted a tierlist for template {template_id}")]
+    TierlistNotFound { address: String, template_id: u64 },
+
+    #[error("Item '{name}' has more than {max} alternative names")]
+    TooManyAltNames { name: String, max: usize },
+
+    #[error("Alternative names of item '{name}' are limited to {max} bytes")]
+    AltNameTooLong { name: String, max: usize },
+
+    #[error("Invalid item count range, min {min} is above max {max}")]
+    InvalidItemCountRange { min: u32, max: u32 },
+
+    #[error("Tier '{name}' is defined more than once")]
+    DuplicateTier { name: String },
+
+    #[error("Tier '{tier_name}' is not defined for this template")]
+    TierDefinitionNotFound { tier_name: String },
+
+    #[error("Templates must keep at least {min} tiers")]
+    TooFewTiers { min: usize },
+
+    #[error("Templates may have at most {max} tiers")]
+    TooManyTiers { max: usize },
+
+    #[error("Invalid color '{color}' for tier '{tier}', expected #RRGGBB")]
+    InvalidTierColor { tier: String, color: String },
+
+    #[error("Emoji of tier '{tier}' is limited to {max} bytes")]
+    TierEmojiTooLong { tier: String, max: usize },
+
+    #[error("At most {max} tierlists may be pinned per template")]
+    PinLimitReached { max: usize },
+
+    #[error("Category '{category}' does not exist")]
+    InvalidCategory { category: String },
+
+    #[error("Category '{category}' is still used by a template")]
+    CategoryInUse { category: String },
+
+    #[error("Invalid language '{language}', expected a lowercase tag of 2 to 5 characters")]
+    InvalidLanguage { language: String },
+
+    #[error("External template ids must be 1 to {max} bytes long")]
+    InvalidExternalId { max: usize },
+
+    #[error("Invalid metadata url '{url}', expected an http(s), ipfs or ar url")]
+    InvalidMetadataUrl { url: String },
+
+    #[error("External template '{external_id}' is already registered")]
+    ExternalTemplateExists { external_id: String },
+
+    #[error("Invalid slug '{slug}', expected 1 to {max} lowercase letters, digits or dashes")]
+    InvalidSlug { slug: String, max: usize },
+
+    #[error("Slug '{slug}' is already taken")]
+    SlugTaken { slug: String },
+
+    #[error("Template has expired")]
+    TemplateExpired {},
+
+    #[error("Template is archived and no longer accepts submissions")]
+    TemplateArchived {},
+
+    #[error("Template is sealed, its tierlists can no longer change")]
+    TemplateSealed {},
+
+    #[error("Reorder must list every template item exactly once")]
+    InvalidReorder {},
+
+    #[error("Tierlist is {size} bytes, at most {max} are allowed")]
+    TierlistTooLarge { size: u64, max: u64 },
+
+    #[error("Template {id} does not exist on {contract}")]
+    RemoteTemplateNotFound { contract: String, id: u64 },
+
+    #[error("Contract {contract} did not return a compatible template: {reason}")]
+    InvalidRemoteTemplate { contract: String, reason: String },
+
+    #[error("Invalid template JSON: {reason}")]
+    InvalidTemplateJson { reason: String },
+
+    #[error("A tierlist for template {template_id} already exists")]
+    TierlistAlreadyExists { template_id: u64 },
+
+    #[error("Invalid default tiers: {reason}")]
+    InvalidDefaultTiers { reason: String },
+
+    #[error("Idempotency keys may be at most {max} bytes long")]
+    IdempotencyKeyTooLong { max: usize },
+
+    #[error("Templates may be created with at most {max} collaborators")]
+    TooManyCollaborators { max: usize },
+
+    #[error("Cursor does not match the {sort} sort")]
+    CursorSortMismatch { sort: String },
+
+    #[error("Tierlist is locked")]
+    TierlistLocked {},
+
+    #[error("Signature does not match the save permit")]
+    InvalidSignature {},
+
+    #[error("Public key does not belong to {owner}")]
+    PubkeyMismatch { owner: String },
+
+    #[error("Invalid nonce {got}, expected {expected}")]
+    InvalidNonce { expected: u64, got: u64 },
+
+    #[error("Comments may be at most {max} characters long")]
+    CommentTooLong { max: usize },
+
+    #[error("Templates must declare their tiers")]
+    TiersRequired {},
+
+    #[error("Submissions are closed for this template")]
+    SubmissionsClosed {},
+
+    #[error("Submission limit of {max} reached for this template")]
+    SubmissionLimitReached { max: u32 },
+
+    #[error("Submission limit can only be raised once submissions exist")]
+    SubmissionLimitLowered {},
+}
+
+/// Why a tierlist does not match its template
+#[derive(Error, Debug, PartialEq)]
+pub enum TierlistValidationError {
+    #[error("it was built for template {actual} but saved against template {expected}")]
+    WrongTemplate { expected: u64, actual: u64 },
+
+    #[error("item '{name}' is not part of the template")]
+    UnknownItem { name: String },
+
+    #[error("item '{name}' is listed more than once")]
+    DuplicateItem { name: String },
+
+    #[error("item '{name}' from the template is missing")]
+    MissingItem { name: String },
+
+    #[error("item '{name}' does not match the template's image")]
+    ItemMismatch { name: String },
+}
+
+/// Why an ownership update was refused, mirroring `cw_ownable::OwnershipError`
+#[derive(Error, Debug, PartialEq)]
+pub enum OwnershipError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Contract ownership has been renounced")]
+    NoOwner {},
+
+    #[error("Caller is not the contract's current owner")]
+    NotOwner {},
+
+    #[error("Caller is not the contract's pending owner")]
+    NotPendingOwner {},
+
+    #[error("There is no pending ownership transfer")]
+    TransferNotFound {},
+
+    #[error("The pending ownership transfer has expired")]
+    TransferExpired {},
+
+    #[error("Ownership transfers cannot expire in the past")]
+    InvalidExpiration {},
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{ContractError, OwnershipError, TierlistValidationError};
+    use cosmwasm_std::{StdError, Uint128};
+
+    fn assert_display(cases: Vec<(ContractError, &str)>) {
+        for (err, expected) in cases {
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_access_error_display() {
+        assert_display(vec![
+            (
+                ContractError::Std(StdError::generic_err("boom")),
+                "Generic error: boom",
+            ),
+            (
+                ContractError::Unauthorized {
+                    reason: "only the admin may ban addresses".to_string(),
+                },
+                "Unauthorized: only the admin may ban addresses",
+            ),
+            (
+                ContractError::Ownership(OwnershipError::NotOwner {}),
+                "Caller is not the contract's current owner",
+            ),
+            (ContractError::AddressBanned {}, "Address is banned"),
+            (
+                ContractError::InvalidSignature {},
+                "Signature does not match the save permit",
+            ),
+            (
+                ContractError::PubkeyMismatch {
+                    owner: "addr1".to_string(),
+                },
+                "Public key does not belong to addr1",
+            ),
+            (
+                ContractError::InvalidNonce {
+                    expected: 2,
+                    got: 1,
+                },
+                "Invalid nonce 1, expected 2",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_template_error_display() {
+        assert_display(vec![
+            (
+                ContractError::InvalidTemplateId { id: 7 },
+                "Template id 7 has never been assigned",
+            ),
+            (
+                ContractError::TemplateNotFound { id: 7 },
+                "Template 7 does not exist",
+            ),
+            (ContractError::TemplateExpired {}, "Template has expired"),
+            (
+                ContractError::TemplateArchived {},
+                "Template is archived and no longer accepts submissions",
+            ),
+            (
+                ContractError::TemplateSealed {},
+                "Template is sealed, its tierlists can no longer change",
+            ),
+            (
+                ContractError::SubmissionsClosed {},
+                "Submissions are closed for this template",
+            ),
+            (
+                ContractError::SubmissionLimitReached { max: 3 },
+                "Submission limit of 3 reached for this template",
+            ),
+            (
+                ContractError::SubmissionLimitLowered {},
+                "Submission limit can only be raised once submissions exist",
+            ),
+            (
+                ContractError::InvalidLanguage {
+                    language: "EN".to_string(),
+                },
+                "Invalid language 'EN', expected a lowercase tag of 2 to 5 characters",
+            ),
+            (
+                ContractError::InvalidCategory {
+                    category: "Cars".to_string(),
+                },
+                "Category 'Cars' does not exist",
+            ),
+            (
+                ContractError::CategoryInUse {
+                    category: "Games".to_string(),
+                },
+                "Category 'Games' is still used by a template",
+            ),
+            (
+                ContractError::InvalidSlug {
+                    slug: "My Template".to_string(),
+                    max: 64,
+                },
+                "Invalid slug 'My Template', expected 1 to 64 lowercase letters, digits or dashes",
+            ),
+            (
+                ContractError::SlugTaken {
+                    slug: "fruit".to_string(),
+                },
+                "Slug 'fruit' is already taken",
+            ),
+            (
+                ContractError::TooManyCollaborators { max: 5 },
+                "Templates may be created with at most 5 collaborators",
+            ),
+            (
+                ContractError::InvalidTemplateJson {
+                    reason: "missing field `title`".to_string(),
+                },
+                "Invalid template JSON: missing field `title`",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_template_item_error_display() {
+        assert_display(vec![
+            (
+                ContractError::NoItems {},
+                "Templates must have at least one item",
+            ),
+            (
+                ContractError::TooManyItems { max: 100 },
+                "Templates may have at most 100 items",
+            ),
+            (
+                ContractError::TooFewItems { min: 2 },
+                "Templates must have at least 2 items",
+            ),
+            (
+                ContractError::DuplicateItem {
+                    name: "A".to_string(),
+                },
+                "Item 'A' is listed more than once",
+            ),
+            (
+                ContractError::DuplicateItemOrder { order: 2 },
+                "Items must not share the same order, 2 is used more than once",
+            ),
+            (
+                ContractError::ItemNotFound {
+                    name: "A".to_string(),
+                },
+                "Item 'A' does not exist in this template",
+            ),
+            (
+                ContractError::TooManyAltNames {
+                    name: "A".to_string(),
+                    max: 5,
+                },
+                "Item 'A' has more than 5 alternative names",
+            ),
+            (
+                ContractError::AltNameTooLong {
+                    name: "A".to_string(),
+                    max: 64,
+                },
+                "Alternative names of item 'A' are limited to 64 bytes",
+            ),
+            (
+                ContractError::InvalidReorder {},
+                "Reorder must list every template item exactly once",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_tier_error_display() {
+        assert_display(vec![
+            (
+                ContractError::InvalidTierLabel {
+                    label: "Z".to_string(),
+                    template_id: 1,
+                },
+                "Invalid tier label 'Z', it is not defined by template 1",
+            ),
+            (
+                ContractError::TierDefinitionNotFound {
+                    tier_name: "S".to_string(),
+                },
+                "Tier 'S' is not defined for this template",
+            ),
+            (
+                ContractError::TooFewTiers { min: 2 },
+                "Templates must keep at least 2 tiers",
+            ),
+            (
+                ContractError::TooManyTiers { max: 10 },
+                "Templates may have at most 10 tiers",
+            ),
+            (
+                ContractError::DuplicateTier {
+                    name: "S".to_string(),
+                },
+                "Tier 'S' is defined more than once",
+            ),
+            (
+                ContractError::InvalidTierColor {
+                    tier: "S".to_string(),
+                    color: "red".to_string(),
+                },
+                "Invalid color 'red' for tier 'S', expected #RRGGBB",
+            ),
+            (
+                ContractError::TierEmojiTooLong {
+                    tier: "S".to_string(),
+                    max: 8,
+                },
+                "Emoji of tier 'S' is limited to 8 bytes",
+            ),
+            (
+                ContractError::InvalidDefaultTiers {
+                    reason: "at most 10 tiers".to_string(),
+                },
+                "Invalid default tiers: at most 10 tiers",
+            ),
+            (
+                ContractError::TiersRequired {},
+                "Templates must declare their tiers",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_tierlist_error_display() {
+        assert_display(vec![
+            (
+                ContractError::InvalidTierlist(TierlistValidationError::MissingItem {
+                    name: "A".to_string(),
+                }),
+                "Invalid tierlist, item 'A' from the template is missing",
+            ),
+            (
+                ContractError::TierlistNotFound {
+                    address: "addr1".to_string(),
+                    template_id: 7,
+                },
+                "addr1 has not submitted a tierlist for template 7",
+            ),
+            (
+                ContractError::TierlistAlreadyExists { template_id: 3 },
+                "A tierlist for template 3 already exists",
+            ),
+            (ContractError::TierlistLocked {}, "Tierlist is locked"),
+            (
+                ContractError::TierlistTooLarge {
+                    size: 600,
+                    max: 512,
+                },
+                "Tierlist is 600 bytes, at most 512 are allowed",
+            ),
+            (
+                ContractError::TooManyTierlists { max: 10 },
+                "At most 10 tierlists may be saved at once",
+            ),
+            (
+                ContractError::CommentTooLong { max: 280 },
+                "Comments may be at most 280 characters long",
+            ),
+            (
+                ContractError::IdempotencyKeyTooLong { max: 64 },
+                "Idempotency keys may be at most 64 bytes long",
+            ),
+            (
+                ContractError::PinLimitReached { max: 5 },
+                "At most 5 tierlists may be pinned per template",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_fee_error_display() {
+        assert_display(vec![
+            (
+                ContractError::BatchFundsNotAccepted {},
+                "Batch saves do not accept funds, save templates with fees one at a time",
+            ),
+            (
+                ContractError::InsufficientFee {
+                    required: Uint128::new(100),
+                    provided: Uint128::new(50),
+                },
+                "Insufficient fee, 100 required but 50 provided",
+            ),
+            (
+                ContractError::InvalidTemplateFee {},
+                "Template fees need a non-zero amount and a denom",
+            ),
+            (
+                ContractError::FundsNotAccepted {},
+                "Template takes no fee, funds are not accepted",
+            ),
+            (
+                ContractError::UnexpectedDenom {
+                    denom: "uatom".to_string(),
+                },
+                "Received unsupported denom 'uatom'",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_external_template_error_display() {
+        assert_display(vec![
+            (
+                ContractError::InvalidExternalId { max: 64 },
+                "External template ids must be 1 to 64 bytes long",
+            ),
+            (
+                ContractError::InvalidMetadataUrl {
+                    url: "ftp://a".to_string(),
+                },
+                "Invalid metadata url 'ftp://a', expected an http(s), ipfs or ar url",
+            ),
+            (
+                ContractError::ExternalTemplateExists {
+                    external_id: "abc".to_string(),
+                },
+                "External template 'abc' is already registered",
+            ),
+            (
+                ContractError::RemoteTemplateNotFound {
+                    contract: "contract1".to_string(),
+                    id: 3,
+                },
+                "Template 3 does not exist on contract1",
+            ),
+            (
+                ContractError::InvalidRemoteTemplate {
+                    contract: "contract1".to_string(),
+                    reason: "unknown variant".to_string(),
+                },
+                "Contract contract1 did not return a compatible template: unknown variant",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_query_error_display() {
+        assert_display(vec![
+            (
+                ContractError::InvalidItemCountRange { min: 10, max: 5 },
+                "Invalid item count range, min 10 is above max 5",
+            ),
+            (
+                ContractError::CursorSortMismatch {
+                    sort: "newest".to_string(),
+                },
+                "Cursor does not match the newest sort",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_validation_error_display() {
+        let cases = vec![
+            (
+                TierlistValidationError::WrongTemplate {
+                    expected: 1,
+                    actual: 2,
+                },
+                "it was built for template 2 but saved against template 1",
+            ),
+            (
+                TierlistValidationError::UnknownItem {
+                    name: "Z".to_string(),
+                },
+                "item 'Z' is not part of the template",
+            ),
+            (
+                TierlistValidationError::DuplicateItem {
+                    name: "A".to_string(),
+                },
+                "item 'A' is listed more than once",
+            ),
+            (
+                TierlistValidationError::MissingItem {
+                    name: "A".to_string(),
+                },
+                "item 'A' from the template is missing",
+            ),
+            (
+                TierlistValidationError::ItemMismatch {
+                    name: "A".to_string(),
+                },
+                "item 'A' does not match the template's image",
+            ),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(err.to_string(), expected);
+        }
+
+        // Validation errors convert into the contract error
+        let err: ContractError = TierlistValidationError::MissingItem {
+            name: "A".to_string(),
+        }
+        .into();
+        assert!(matches!(err, ContractError::InvalidTierlist(_)));
+    }
+
+    #[test]
+    fn test_ownership_error_display() {
+        let cases = vec![
+            (
+                OwnershipError::NoOwner {},
+                "Contract ownership has been renounced",
+            ),
+            (
+                OwnershipError::NotOwner {},
+                "Caller is not the contract's current owner",
+            ),
+            (
+                OwnershipError::NotPendingOwner {},
+                "Caller is not the contract's pending owner",
+            ),
+            (
+                OwnershipError::TransferNotFound {},
+                "There is no pending ownership transfer",
+            ),
+            (
+                OwnershipError::TransferExpired {},
+                "The pending ownership transfer has expired",
+            ),
+            (
+                OwnershipError::InvalidExpiration {},
+                "Ownership transfers cannot expire in the past",
+            ),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(err.to_string(), expected);
+        }
+    }
 }
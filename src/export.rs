@@ -0,0 +1,465 @@
+//! Canonical binary encodings of stored records for cross-chain verifiers.
+//!
+//! Unlike the JSON responses these do not depend on serde field ordering. Every export
+//! starts with `EXPORT_VERSION`, followed by the record's fields in the order listed on
+//! each encoder. Integers are big endian, strings and byte strings are prefixed with
+//! their length as a u32, lists with their count as a u32, and options and bools are a
+//! single 0/1 byte followed by the value when present. Timestamps are u64 nanoseconds.
+
+use cosmwasm_std::{Addr, Binary, StdError, StdResult, Timestamp};
+
+use crate::state::{
+    RevisionMeta, TemplateVisibility, TierDef, Tierlist, TierlistItem, TierlistTemplate,
+};
+
+/// Bumped whenever the encoding of any record changes
+pub const EXPORT_VERSION: u8 = 5;
+
+/// Encodes id, title, items, creator, tier definitions, max submissions, submissions
+/// open, content hash, sealed at, expires at, category, language, archived, forked from,
+/// source, created at, strict uniqueness and whether it is unlisted. Items are kept in their stored order.
+pub fn encode_template(template: &TierlistTemplate) -> Binary {
+    let mut enc = Encoder::new();
+    enc.u64(template.id);
+    enc.str(&template.title);
+    enc.list(&template.items, Encoder::item);
+    enc.str(template.creator.as_str());
+    enc.option(&template.tier_definitions, |enc, tiers| {
+        enc.list(tiers, Encoder::tier_def)
+    });
+    enc.option(&template.max_submissions, |enc, max| enc.u32(*max));
+    enc.bool(template.submissions_open);
+    enc.bytes(&template.content_hash);
+    enc.option(&template.sealed_at, |enc, (height, time)| {
+        enc.u64(*height);
+        enc.u64(time.nanos());
+    });
+    enc.option(&template.expires_at, |enc, height| enc.u64(*height));
+    enc.option(&template.category, |enc, category| enc.str(category));
+    enc.option(&template.language, |enc, language| enc.str(language));
+    enc.bool(template.archived);
+    enc.option(&template.forked_from, |enc, id| enc.u64(*id));
+    enc.option(&template.source, |enc, (contract, id)| {
+        enc.str(contract);
+        enc.u64(*id);
+    });
+    enc.option(&template.created_at, |enc, time| enc.u64(time.nanos()));
+    enc.bool(template.strict_uniqueness);
+    enc.bool(template.visibility == TemplateVisibility::Unlisted);
+    enc.finish()
+}
+
+pub fn decode_template(data: &[u8]) -> StdResult<TierlistTemplate> {
+    let mut dec = Decoder::new(data)?;
+    let template = TierlistTemplate {
+        id: dec.u64()?,
+        title: dec.string()?,
+        items: dec.list(Decoder::item)?,
+        creator: Addr::unchecked(dec.string()?),
+        tier_definitions: dec.option(|dec| dec.list(Decoder::tier_def))?,
+        max_submissions: dec.option(Decoder::u32)?,
+        submissions_open: dec.bool()?,
+        content_hash: Binary::from(dec.bytes()?),
+        sealed_at: dec.option(|dec| Ok((dec.u64()?, Timestamp::from_nanos(dec.u64()?))))?,
+        expires_at: dec.option(Decoder::u64)?,
+        category: dec.option(Decoder::string)?,
+        language: dec.option(Decoder::string)?,
+        archived: dec.bool()?,
+        forked_from: dec.option(Decoder::u64)?,
+        source: dec.option(|dec| Ok((dec.string()?, dec.u64()?)))?,
+        created_at: dec.option(|dec| Ok(Timestamp::from_nanos(dec.u64()?)))?,
+        strict_uniqueness: dec.bool()?,
+        visibility: match dec.bool()? {
+            true => TemplateVisibility::Unlisted,
+            false => TemplateVisibility::Public,
+        },
+    };
+    dec.finish()?;
+    Ok(template)
+}
+
+/// Encodes template id, (item, tier) assignments in stored order, revisions and locked
+pub fn encode_tierlist(tierlist: &Tierlist) -> Binary {
+    let mut enc = Encoder::new();
+    enc.u64(tierlist.template_id);
+    enc.list(&tierlist.items_to_tiers, |enc, (item, tier)| {
+        enc.item(item);
+        enc.str(tier);
+    });
+    enc.list(&tierlist.revisions, |enc, revision| {
+        enc.u64(revision.revision);
+        enc.u64(revision.height);
+        enc.u64(revision.time.nanos());
+    });
+    enc.bool(tierlist.locked);
+    enc.finish()
+}
+
+pub fn decode_tierlist(data: &[u8]) -> StdResult<Tierlist> {
+    let mut dec = Decoder::new(data)?;
+    let tierlist = Tierlist {
+        template_id: dec.u64()?,
+        items_to_tiers: dec.list(|dec| Ok((dec.item()?, dec.string()?)))?,
+        revisions: dec.list(|dec| {
+            Ok(RevisionMeta {
+                revision: dec.u64()?,
+                height: dec.u64()?,
+                time: Timestamp::from_nanos(dec.u64()?),
+            })
+        })?,
+        locked: dec.bool()?,
+    };
+    dec.finish()?;
+    Ok(tierlist)
+}
+
+struct Encoder(Vec<u8>);
+
+impl Encoder {
+    fn new() -> Self {
+        Encoder(vec![EXPORT_VERSION])
+    }
+
+    fn finish(self) -> Binary {
+        Binary::from(self.0)
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.0.push(value as u8);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.0.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.0.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.u32(value.len() as u32);
+        self.0.extend_from_slice(value);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    fn option<T>(&mut self, value: &Option<T>, f: impl FnOnce(&mut Self, &T)) {
+        self.bool(value.is_some());
+        if let Some(value) = value {
+            f(self, value);
+        }
+    }
+
+    fn list<T>(&mut self, values: &[T], f: impl Fn(&mut Self, &T)) {
+        self.u32(values.len() as u32);
+        for value in values {
+            f(self, value);
+        }
+    }
+
+    /// Name, image url, order and alt names
+    fn item(&mut self, item: &TierlistItem) {
+        self.str(&item.name);
+        self.option(&item.image_url, |enc, url| enc.str(url));
+        self.u32(item.order);
+        self.option(&item.alt_names, |enc, alt_names| {
+            enc.list(alt_names, |enc, (language, name)| {
+                enc.str(language);
+                enc.str(name);
+            })
+        });
+    }
+
+    /// Name, color and emoji
+    fn tier_def(&mut self, tier: &TierDef) {
+        self.str(&tier.name);
+        self.option(&tier.color, |enc, color| enc.str(color));
+        self.option(&tier.emoji, |enc, emoji| enc.str(emoji));
+    }
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn new(data: &'a [u8]) -> StdResult<Self> {
+        let mut dec = Decoder { data };
+        let version = dec.u8()?;
+        if version != EXPORT_VERSION {
+            return Err(parse_err(format!("unsupported version {}", version)));
+        }
+        Ok(dec)
+    }
+
+    fn finish(self) -> StdResult<()> {
+        if !self.data.is_empty() {
+            return Err(parse_err(format!("{} trailing bytes", self.data.len())));
+        }
+        Ok(())
+    }
+
+    fn take(&mut self, len: usize) -> StdResult<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(parse_err("unexpected end of data".to_string()));
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> StdResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn bool(&mut self) -> StdResult<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(parse_err(format!("invalid tag {}", tag))),
+        }
+    }
+
+    fn u32(&mut self) -> StdResult<u32> {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Ok(u32::from_be_bytes(buf))
+    }
+
+    fn u64(&mut self) -> StdResult<u64> {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Ok(u64::from_be_bytes(buf))
+    }
+
+    fn bytes(&mut self) -> StdResult<Vec<u8>> {
+        let len = self.u32()? as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn string(&mut self) -> StdResult<String> {
+        String::from_utf8(self.bytes()?).map_err(|_| parse_err("invalid utf-8".to_string()))
+    }
+
+    fn option<T>(&mut self, f: impl FnOnce(&mut Self) -> StdResult<T>) -> StdResult<Option<T>> {
+        if self.bool()? {
+            Ok(Some(f(self)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn list<T>(&mut self, f: impl Fn(&mut Self) -> StdResult<T>) -> StdResult<Vec<T>> {
+        let count = self.u32()?;
+        // Every element takes at least a byte, so a count beyond that is corrupt
+        if count as usize > self.data.len() {
+            return Err(parse_err(format!("list of {} overruns the data", count)));
+        }
+        (0..count).map(|_| f(self)).collect()
+    }
+
+    fn item(&mut self) -> StdResult<TierlistItem> {
+        Ok(TierlistItem {
+            name: self.string()?,
+            image_url: self.option(Decoder::string)?,
+            order: self.u32()?,
+            alt_names: self.option(|dec| dec.list(|dec| Ok((dec.string()?, dec.string()?))))?,
+        })
+    }
+
+    fn tier_def(&mut self) -> StdResult<TierDef> {
+        Ok(TierDef {
+            name: self.string()?,
+            color: self.option(Decoder::string)?,
+            emoji: self.option(Decoder::string)?,
+        })
+    }
+}
+
+fn parse_err(msg: String) -> StdError {
+    StdError::parse_err("export", msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(name: &str) -> TierlistItem {
+        TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }
+    }
+
+    fn full_template() -> TierlistTemplate {
+        TierlistTemplate {
+            id: 7,
+            title: "Fruits ü".to_string(),
+            items: vec![
+                TierlistItem {
+                    name: "Banana".to_string(),
+                    image_url: Some("https://example.com/b.png".to_string()),
+                    order: 2,
+                    alt_names: Some(vec![("es".to_string(), "Plátano".to_string())]),
+                },
+                item("Apple"),
+            ],
+            creator: Addr::unchecked("addr1"),
+            tier_definitions: Some(vec![
+                TierDef {
+                    name: "S".to_string(),
+                    color: Some("#ff0000".to_string()),
+                    emoji: Some("🔥".to_string()),
+                },
+                "A".into(),
+            ]),
+            max_submissions: Some(10),
+            submissions_open: false,
+            sealed_at: Some((12, Timestamp::from_seconds(34))),
+            expires_at: Some(56),
+            category: Some("Food".to_string()),
+            language: Some("pt-br".to_string()),
+            archived: true,
+            forked_from: Some(3),
+            source: Some(("contract1".to_string(), 4)),
+            created_at: Some(Timestamp::from_seconds(78)),
+            strict_uniqueness: true,
+            visibility: TemplateVisibility::Unlisted,
+            content_hash: Binary::from(vec![1, 2, 3]),
+        }
+    }
+
+    fn empty_template() -> TierlistTemplate {
+        TierlistTemplate {
+            id: 0,
+            title: String::new(),
+            items: vec![],
+            creator: Addr::unchecked(""),
+            tier_definitions: None,
+            max_submissions: None,
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+        }
+    }
+
+    fn full_tierlist() -> Tierlist {
+        Tierlist {
+            template_id: 7,
+            items_to_tiers: vec![
+                (item("Banana"), "S".to_string()),
+                (item("Apple"), String::new()),
+            ],
+            revisions: vec![RevisionMeta {
+                revision: 1,
+                height: 12,
+                time: Timestamp::from_nanos(34),
+            }],
+            locked: true,
+        }
+    }
+
+    #[test]
+    fn test_template_round_trip() {
+        for template in [full_template(), empty_template()] {
+            let encoded = encode_template(&template);
+            assert_eq!(decode_template(&encoded).unwrap(), template);
+        }
+
+        let mut template = empty_template();
+        template.tier_definitions = Some(vec![]);
+        template.items = vec![item("A")];
+        template.items[0].alt_names = Some(vec![]);
+        let encoded = encode_template(&template);
+        assert_eq!(decode_template(&encoded).unwrap(), template);
+    }
+
+    #[test]
+    fn test_tierlist_round_trip() {
+        let tierlist = full_tierlist();
+        let encoded = encode_tierlist(&tierlist);
+        assert_eq!(decode_tierlist(&encoded).unwrap(), tierlist);
+
+        let empty = Tierlist {
+            template_id: 0,
+            items_to_tiers: vec![],
+            revisions: vec![],
+            locked: false,
+        };
+        let encoded = encode_tierlist(&empty);
+        assert_eq!(decode_tierlist(&encoded).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_encoding_layout() {
+        let encoded = encode_tierlist(&Tierlist {
+            template_id: 1,
+            items_to_tiers: vec![(item("A"), "S".to_string())],
+            revisions: vec![],
+            locked: true,
+        });
+        let expected = [
+            &[EXPORT_VERSION][..],
+            &1u64.to_be_bytes(), // template id
+            &1u32.to_be_bytes(), // one assignment
+            &1u32.to_be_bytes(), // item name length
+            b"A",                // item name
+            &[0],                // no image url
+            &0u32.to_be_bytes(), // order
+            &[0],                // no alt names
+            &1u32.to_be_bytes(), // tier length
+            b"S",                // tier
+            &0u32.to_be_bytes(), // no revisions
+            &[1],                // locked
+        ]
+        .concat();
+        assert_eq!(encoded.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed() {
+        let encoded = encode_template(&full_template()).to_vec();
+        // Every truncation fails rather than decoding a partial record
+        for len in 0..encoded.len() {
+            assert!(decode_template(&encoded[..len]).is_err());
+        }
+
+        let mut trailing = encoded.clone();
+        trailing.push(0);
+        assert!(decode_template(&trailing).is_err());
+
+        let mut version = encoded.clone();
+        version[0] = EXPORT_VERSION + 1;
+        assert!(decode_template(&version).is_err());
+
+        let tierlist = encode_tierlist(&full_tierlist()).to_vec();
+        let mut tag = tierlist.clone();
+        *tag.last_mut().unwrap() = 2;
+        assert!(decode_tierlist(&tag).is_err());
+
+        let mut utf8 = encode_tierlist(&full_tierlist()).to_vec();
+        // First byte of the first item's name
+        utf8[1 + 8 + 4 + 4] = 0xff;
+        assert!(decode_tierlist(&utf8).is_err());
+
+        let mut count = tierlist;
+        count[1 + 8..1 + 8 + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(decode_tierlist(&count).is_err());
+
+        // A template is not a tierlist
+        assert!(decode_tierlist(&encoded).is_err());
+    }
+}
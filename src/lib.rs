@@ -1,6 +1,10 @@
 pub mod contract;
 mod error;
+pub mod export;
 pub mod msg;
+pub mod ownership;
 pub mod state;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing;
 
-pub use crate::error::ContractError;
+pub use crate::error::{ContractError, OwnershipError};
@@ -1,10 +1,50 @@
-use crate::state::{Tierlist, TierlistItem, TierlistTemplate};
+use crate::ownership::Action;
+use crate::state::{
+    Config, ExternalTemplateRef, ItemComparison, TemplateVisibility, TierDef, Tierlist,
+    TierlistItem, TierlistTemplate,
+};
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
+use cw2::ContractVersion;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
     pub admin_address: String,
+    #[serde(default)]
+    pub require_template_tiers: bool,
+    #[serde(default)]
+    pub hide_creators: bool,
+    pub max_tierlist_bytes: Option<u64>, // Defaults to `state::DEFAULT_MAX_TIERLIST_BYTES`
+}
+
+/// Order of the `Templates` listing
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateSort {
+    /// Ascending template id
+    Id,
+    /// Most recently created first. Templates created before creation times were tracked
+    /// are left out
+    Newest,
+}
+
+/// Position in a sorted `Templates` listing
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum TemplateCursor {
+    /// Template id, for `TemplateSort::Id`
+    Id(u64),
+    /// Creation time in seconds and template id, for `TemplateSort::Newest`
+    Newest(u64, u64),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrateMsg {
+    /// Rewrites creators and tierlist keys stored before addresses were normalized. Handles
+    /// up to `limit` records per migration, repeat until `MigrationStatus` reports it done.
+    NormalizeAddresses { limit: u32 },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -13,6 +53,68 @@ pub enum ExecuteMsg {
     CreateTemplate {
         title: String,
         items: Vec<TierlistItem>,
+        tier_definitions: Option<Vec<TierDef>>,
+        max_submissions: Option<u32>,
+        language: Option<String>,
+        /// Allows items sharing a name as long as their images differ, defaults to false
+        strict_uniqueness: Option<bool>,
+        /// Defaults to `TemplateVisibility::Public`
+        visibility: Option<TemplateVisibility>,
+    },
+    /// Creates a template whose tierlist saves must pay `fee_amount` of `fee_token`, the
+    /// payment is forwarded to the creator
+    CreateTemplateWithFee {
+        title: String,
+        items: Vec<TierlistItem>,
+        tier_definitions: Option<Vec<TierDef>>,
+        max_submissions: Option<u32>,
+        language: Option<String>,
+        fee_token: String,
+        fee_amount: Uint128,
+        /// Defaults to `TemplateVisibility::Public`
+        visibility: Option<TemplateVisibility>,
+    },
+    /// Creates a template whose collaborators may edit it like its creator
+    CreateTemplateWithCollaborators {
+        title: String,
+        items: Vec<TierlistItem>,
+        collaborators: Vec<String>,
+        /// Defaults to `TemplateVisibility::Public`
+        visibility: Option<TemplateVisibility>,
+    },
+    /// Creates a template with no creator attributed, only the admin may edit or delete it
+    CreateAnonymousTemplate {
+        title: String,
+        items: Vec<TierlistItem>,
+        /// Defaults to `TemplateVisibility::Public`
+        visibility: Option<TemplateVisibility>,
+    },
+    /// Creates a template from a `TierlistTemplate` JSON document. Only the title, items,
+    /// tier definitions, submission limit and language are used, the id is allocated and
+    /// the sender becomes the creator.
+    #[serde(rename = "import_template_from_json")]
+    ImportTemplateFromJSON {
+        json: String,
+        /// Defaults to `TemplateVisibility::Public`
+        visibility: Option<TemplateVisibility>,
+    },
+    /// Creates a template with the items of `template_id` on another instance of this
+    /// contract, recording where they came from in the template's `source`
+    CreateTemplateFromRemote {
+        title: String,
+        contract: String,
+        template_id: u64,
+        /// Defaults to `TemplateVisibility::Public`
+        visibility: Option<TemplateVisibility>,
+    },
+    /// Copies a template's title, items and tiers into a new template owned by the sender
+    CloneTemplate {
+        id: u64,
+        /// Defaults to the visibility of the template being cloned
+        visibility: Option<TemplateVisibility>,
+    },
+    RemoveTemplateFee {
+        template_id: u64,
     },
     DeleteTemplate {
         id: u64,
@@ -21,35 +123,536 @@ pub enum ExecuteMsg {
         id: u64,
         title: String,
         items: Vec<TierlistItem>,
+        tier_definitions: Option<Vec<TierDef>>,
+        max_submissions: Option<u32>,
+        language: Option<String>,
+        visibility: Option<TemplateVisibility>, // Keeps the current visibility when None
+    },
+    /// Updates only the provided fields, sending neither is a no-op
+    PatchTemplate {
+        id: u64,
+        title: Option<String>,
+        items: Option<Vec<TierlistItem>>,
     },
+    /// Set `replace` to false to fail instead of overwriting an existing tierlist
     SaveTierlist {
         tierlist: Tierlist,
+        replace: Option<bool>, // Defaults to true
+        /// Repeating the key of the tierlist's last save makes the save a no-op
+        idempotency_key: Option<String>,
+        /// Saves identical to the stored tierlist are skipped unless forced, defaults to false
+        force: Option<bool>,
+    },
+    /// Saves `tierlist` as `owner`, authorised by the owner's secp256k1 signature over the
+    /// sha256 hash of the JSON encoded `SavePermit`. Lets a relayer pay gas for the owner.
+    SaveTierlistFor {
+        owner: String,
+        tierlist: Tierlist,
+        signature: Binary,
+        pubkey: Binary,
+        nonce: u64,
+    },
+    SaveTierlistAndComment {
+        tierlist: Tierlist,
+        comment: String,
+    },
+    /// Saves several tierlists for the sender, replacing existing ones. Any invalid tierlist
+    /// fails the whole message unless `best_effort` is set, which skips it instead. Templates
+    /// with fees must be saved with `SaveTierlist`.
+    SaveTierlists {
+        tierlists: Vec<Tierlist>,
+        best_effort: Option<bool>,
+    },
+    ReorderTemplateItems {
+        id: u64,
+        order: Vec<String>,
+    },
+    /// Saves the caller's tierlist for `to_template_id` with the tiers of their tierlist for
+    /// `from_template_id`, matching items by name. Other items are left unassigned.
+    CopyRankings {
+        from_template_id: u64,
+        to_template_id: u64,
+    },
+    /// Lists the caller's tierlist in `TierlistsForTemplate`, tierlists are private until published
+    PublishTierlist {
+        template_id: u64,
+    },
+    UnpublishTierlist {
+        template_id: u64,
+    },
+    SetItemOrder {
+        template_id: u64,
+        orders: Vec<(String, u32)>,
+    },
+    /// Appends a tier to the template's definitions, defining them if the template had none
+    AddTierDefinition {
+        template_id: u64,
+        tier_name: String,
+    },
+    /// Drops one tier from the template's definitions, saved tierlists using it are kept
+    /// but can no longer be re-saved with it
+    RemoveTierDefinition {
+        template_id: u64,
+        tier_name: String,
+    },
+    SetSubmissionsOpen {
+        id: u64,
+        open: bool,
+    },
+    SetTemplateExpiry {
+        id: u64,
+        expires_at: Option<u64>,
+    },
+    /// Hides the template from listings and stops new submissions, existing tierlists are kept
+    ArchiveTemplate {
+        id: u64,
+    },
+    RestoreTemplate {
+        id: u64,
+    },
+    SetTemplateCategory {
+        id: u64,
+        category: Option<String>,
+    },
+    /// Gives the template a unique slug to look it up by with `TemplateBySlug`, the only
+    /// lookup besides the id that returns unlisted templates. None removes the slug.
+    SetTemplateSlug {
+        id: u64,
+        slug: Option<String>,
+    },
+    /// Admin only. Removals are applied first and fail while a template still uses the category.
+    UpdateCategories {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Stops further saves to the tierlist. `owner` defaults to the sender, only the admin
+    /// may name another owner.
+    LockTierlist {
+        template_id: u64,
+        owner: Option<String>,
+    },
+    UnlockTierlist {
+        template_id: u64,
+        owner: Option<String>,
+    },
+    SyncTierlist {
+        template_id: u64,
+    },
+    /// Deletes all but the `keep_latest` most recent history snapshots, defaults to 0
+    ClearTierlistHistory {
+        template_id: u64,
+        keep_latest: Option<u32>,
+    },
+    AdminDeleteTierlist {
+        address: String,
+        template_id: u64,
+    },
+    AdminClearTierlistHistory {
+        address: String,
+        template_id: u64,
+        keep_latest: Option<u32>,
+    },
+    /// Clears the comment and blanks every tier label, keeping the items
+    AdminRedactTierlist {
+        address: String,
+        template_id: u64,
+    },
+    /// Admin only, applies every field that is set in one go
+    UpdateConfig {
+        require_template_tiers: Option<bool>,
+        hide_creators: Option<bool>,
+        max_tierlist_bytes: Option<u64>,
+        /// Zero removes the minimum
+        min_items_per_template: Option<u32>,
+    },
+    /// Admin only, labels of templates without tier definitions must be one of these.
+    /// An empty list removes the defaults
+    SetDefaultTiers {
+        tiers: Vec<String>,
+    },
+    BanAddress {
+        address: String,
+    },
+    UnbanAddress {
+        address: String,
+    },
+    PinTierlist {
+        address: String,
+        template_id: u64,
+    },
+    UnpinTierlist {
+        address: String,
+        template_id: u64,
+    },
+    /// Irreversibly freezes every tierlist submitted against the template, anyone may seal
+    /// an expired template
+    SealTemplate {
+        id: u64,
     },
+    PurgeTemplateTierlists {
+        template_id: u64,
+        limit: u32,
+    },
+    /// Records a template hosted off-chain so it can be discovered on-chain. Each
+    /// `external_id` can only be registered once.
+    RegisterExternalTemplate {
+        external_id: String,
+        metadata_url: String,
+    },
+    /// Proposes, accepts or renounces contract ownership, see `ownership::Action`
+    UpdateOwnership(Action),
+}
+
+/// Payload an owner signs to authorise `ExecuteMsg::SaveTierlistFor`.
+///
+/// The signed digest is the sha256 of this struct encoded as compact JSON with the fields
+/// in the order declared here, as produced by `cosmwasm_std::to_binary`. `tierlist_digest`
+/// is the sha256 of the tierlist encoded the same way, base64 in the JSON. Query
+/// `SavePermitDigest` rather than rebuilding it client side.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SavePermit {
+    pub contract_address: String,
+    pub chain_id: String,
+    pub template_id: u64,
+    pub tierlist_digest: Binary, // sha256 of the JSON encoded tierlist
+    pub nonce: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
+    /// Returns a `ConfigResponse`
     Config {},
+    /// Returns an `ownership::Ownership`
+    Ownership {},
+    /// Tiers used for templates without tier definitions, empty when none are set
+    DefaultTiers {},
+    /// Progress of `MigrateMsg::NormalizeAddresses`, returns a `state::MigrationStatus`
+    MigrationStatus {},
+    /// The cw2 contract name and version
+    Version {},
+    /// Id the next created template will get, returns a `u64`. Ids only ever increase,
+    /// deleting a template never frees its id for reuse.
+    NextTemplateId {},
+    /// Config and the featured templates in one call, the limit is capped at 30. Templates
+    /// with pinned tierlists come first, then the newest public templates that are not archived
+    Bootstrap {
+        limit: Option<u32>,
+    },
+    /// `strict` errors with not found instead of returning None for a missing template
     Template {
         id: u64,
+        strict: Option<bool>,
+    },
+    /// Returns a `TemplateResponse` for the template with the slug, None when no template has it
+    TemplateBySlug {
+        slug: String,
+    },
+    TemplateSummary {
+        id: u64,
+    },
+    TemplateHash {
+        id: u64,
+    },
+    TemplateFee {
+        template_id: u64,
     },
+    TemplateWithTierlistCount {
+        id: u64,
+    },
+    /// `strict` errors with not found instead of returning None for a missing template
     TierlistFromTemplate {
         id: u64,
+        strict: Option<bool>,
     },
+    /// Builds and validates a tierlist from the template and (item name, tier) assignments
+    /// without storing it
+    PreviewFromTemplate {
+        id: u64,
+        assignments: Vec<(String, String)>,
+    },
+    /// `strict` errors with not found instead of returning None for a missing tierlist
     Tierlist {
         address: String,
         id: u64,
+        strict: Option<bool>,
+    },
+    TierlistWithTemplate {
+        address: String,
+        template_id: u64,
+    },
+    /// Like `Tierlist` with the items in the template's display order, returns a
+    /// `TierlistResponse`
+    TierlistOrdered {
+        address: String,
+        id: u64,
+    },
+    /// Whether the address has saved a tierlist for the template, without loading it
+    HasTierlist {
+        address: String,
+        template_id: u64,
+    },
+    /// Hex SHA-256 of the tierlist's assignments, stable across submission order
+    TierlistFingerprint {
+        address: String,
+        id: u64,
+    },
+    /// Number of addresses with a tierlist saved for the template
+    ParticipantCount {
+        template_id: u64,
+    },
+    /// Canonical binary encoding of the template, see `export::encode_template`
+    ExportTemplate {
+        id: u64,
+    },
+    /// Canonical binary encoding of the tierlist, see `export::encode_tierlist`
+    ExportTierlist {
+        address: String,
+        template_id: u64,
+    },
+    /// Nonce the address' next save permit must carry
+    Nonce {
+        address: String,
+    },
+    /// Digest the owner must sign for `ExecuteMsg::SaveTierlistFor` with their current nonce
+    SavePermitDigest {
+        owner: String,
+        tierlist: Tierlist,
+    },
+    /// The submission's items grouped by tier, see `Tierlist::group_by_tier` for the order
+    TierlistByTiers {
+        address: String,
+        template_id: u64,
+    },
+    TierlistRevision {
+        address: String,
+        template_id: u64,
+        revision: u64,
+    },
+    /// Number of revisions the tierlist has been saved at, which keeps counting past the
+    /// `state::MAX_HISTORY_SNAPSHOTS` snapshots kept
+    TierlistHistoryCount {
+        address: String,
+        template_id: u64,
+    },
+    /// Kept snapshots of overwritten revisions as (revision, tierlist), oldest first
+    TierlistHistory {
+        address: String,
+        template_id: u64,
+        start_after_version: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// A user's submissions with their templates, deleted templates are returned as None.
+    /// Submissions to unlisted templates are left out.
+    TemplatesWithUserTierlist {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
     },
     TierlistsByAddress {
         address: String,
         start_after: Option<u64>,
         limit: Option<u32>,
     },
+    /// Continue with the cursor of the last template returned, cursors only work with the
+    /// sort they came from
     Templates {
+        start_after: Option<TemplateCursor>,
+        limit: Option<u32>,
+        open_only: Option<bool>,
+        category: Option<String>,
+        language: Option<String>,
+        include_unspecified: Option<bool>, // Adds templates without a language to a language filter
+        sort: Option<TemplateSort>,        // Defaults to `TemplateSort::Id`
+    },
+    Categories {},
+    /// Archived templates are left out of the other listings
+    ArchivedTemplates {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    AnonymousTemplates {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Templates created in `[start, end)` at second precision, oldest first. Page with the
+    /// `(seconds, id)` of the last template returned. Templates created before creation
+    /// times were tracked are never listed
+    TemplatesCreatedBetween {
+        start: Timestamp,
+        end: Timestamp,
+        start_after: Option<(u64, u64)>,
+        limit: Option<u32>,
+    },
+    /// Templates with an item named exactly `item_name`
+    TemplatesContainingItem {
+        item_name: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Ids of the listed templates cloned directly from `id`
+    TemplateForks {
+        id: u64,
+    },
+    /// Templates the address may edit as a collaborator
+    CollaboratingTemplates {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    ExternalTemplate {
+        external_id: String,
+    },
+    /// Registered external templates ordered by external id
+    ExternalTemplates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Combines the template filters in one query. `limit` bounds the number of templates
+    /// scanned rather than matched and is capped at 30, so a page may hold fewer than `limit`
+    /// results. Continue from `last_scanned` until it is `None`.
+    SearchTemplates {
+        creator: Option<String>,
+        open_only: Option<bool>,
+        language: Option<String>,
+        include_unspecified: Option<bool>,
+        include_archived: Option<bool>,
+        /// Adds the creator's unlisted templates when filtering by creator, ignored otherwise.
+        /// This makes them discoverable by anyone who knows the creator's address.
+        include_unlisted: Option<bool>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Templates whose item count is within the inclusive bounds. As with `SearchTemplates`,
+    /// `limit` bounds the number of templates scanned and is capped at 30, continue from
+    /// `last_scanned` until it is `None`.
+    TemplatesByItemCount {
+        min_items: Option<u32>,
+        max_items: Option<u32>,
         start_after: Option<u64>,
         limit: Option<u32>,
     },
+    ItemDistribution {
+        template_id: u64,
+        item_name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Every stored tierlist across all users as (address, template id, tierlist), the limit
+    /// is capped at 30. Queries carry no sender, so this is open to anyone.
+    AllTierlists {
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    },
+    TemplateParticipants {
+        template_id: u64,
+        start_after_address: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Published tierlists for a template, `include_private` adds unpublished ones. Queries
+    /// carry no sender, so `include_private` is meant for admin tooling but cannot be enforced.
+    TierlistsForTemplate {
+        template_id: u64,
+        start_after_address: Option<String>,
+        limit: Option<u32>,
+        include_private: bool,
+    },
+    TierlistsWithComments {
+        template_id: u64,
+        start_after_address: Option<String>,
+        limit: Option<u32>,
+    },
+    PinnedTierlists {
+        template_id: u64,
+    },
+    IsBanned {
+        address: String,
+    },
+    BannedAddresses {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Errors if either address has not submitted a tierlist for the template
+    CompareTierlists {
+        template_id: u64,
+        address_a: String,
+        address_b: String,
+    },
+    /// `CompareTierlists` condensed to a count and percentage, items both left unassigned
+    /// count as agreeing. Errors if either address has not submitted a tierlist.
+    TierlistAgreementScore {
+        template_id: u64,
+        address_a: String,
+        address_b: String,
+    },
+    /// Agreement between the address's tierlist and a page of other submissions, most similar
+    /// first. Each page scans at most 30 submissions, merge pages for a full leaderboard.
+    MostSimilar {
+        template_id: u64,
+        address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Tier -> count for each template item over a page of submissions, paged by submitter
+    /// address and by item name. Both limits are capped at 30, merge the distributions across
+    /// submitter pages to count every submission.
+    AggregateRankings {
+        template_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        item_start_after: Option<String>,
+        item_limit: Option<u32>,
+    },
+    /// Items ordered by how divided a page of submissions is over their tier, paged by
+    /// submitter address with the limit capped at 30. Merge the distributions across pages
+    /// to rank over every submission.
+    ControversialItems {
+        template_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Items ranked by their average tier score over a page of submissions, see
+    /// `TierlistTemplate::tier_score`. Paged by submitter address with the limit capped at
+    /// 30, sum `total_score` and `placements` across pages to rank over every submission.
+    WeightedConsensus {
+        template_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+/// The client facing view of `Config`, kept stable as the stored config changes
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    pub admin_address: Option<String>, // The contract owner, None once renounced
+    #[serde(default)]
+    pub require_template_tiers: bool,
+    #[serde(default)]
+    pub hide_creators: bool,
+    pub max_tierlist_bytes: u64,
+    pub min_items_per_template: Option<u32>,
+    /// cw2 name and version of the deployed code
+    pub contract_name: String,
+    pub contract_version: String,
+}
+
+impl ConfigResponse {
+    pub fn new(config: Config, owner: Option<Addr>, version: ContractVersion) -> Self {
+        ConfigResponse {
+            admin_address: owner.map(Addr::into_string),
+            require_template_tiers: config.require_template_tiers,
+            hide_creators: config.hide_creators,
+            max_tierlist_bytes: config.max_tierlist_bytes,
+            min_items_per_template: config.min_items_per_template,
+            contract_name: version.contract,
+            contract_version: version.version,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExternalTemplateResponse {
+    pub template: Option<ExternalTemplateRef>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -57,7 +660,200 @@ pub struct TemplateResponse {
     pub template: Option<TierlistTemplate>,
 }
 
+/// Lightweight view of a template without its items
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplateSummary {
+    pub id: u64,
+    pub title: String,
+    pub creator: Addr,
+    pub item_count: u64,
+    pub submission_count: u64,
+    pub remaining_submissions: Option<u64>, // None when submissions are unlimited
+    pub content_hash: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplateSummaryResponse {
+    pub summary: Option<TemplateSummary>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplateWithCountResponse {
+    pub template: Option<TierlistTemplate>,
+    pub tierlist_count: u64,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct TierlistResponse {
     pub tierlist: Option<Tierlist>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplateForksResponse {
+    pub forks: Vec<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ParticipantCountResponse {
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NonceResponse {
+    pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TierlistFingerprintResponse {
+    pub fingerprint: String, // See `Tierlist::fingerprint`
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HasTierlistResponse {
+    pub exists: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SearchTemplatesResponse {
+    pub templates: Vec<(u64, TierlistTemplate)>,
+    pub last_scanned: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplatesByItemCountResponse {
+    pub templates: Vec<(u64, TierlistTemplate)>,
+    pub last_scanned: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TierlistWithTemplateResponse {
+    pub template: Option<TierlistTemplate>,
+    pub tierlist: Option<Tierlist>,
+}
+
+/// (tier, items) groups, unassigned items are grouped last under a tier named ""
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TierlistByTiersResponse {
+    pub tiers: Vec<(TierDef, Vec<TierlistItem>)>,
+}
+
+/// A submission with its template, `template` is None once the template has been deleted
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TierlistWithTemplate {
+    pub template_id: u64,
+    pub template: Option<TierlistTemplate>,
+    pub tierlist: Tierlist,
+}
+
+/// Tier -> count for one item over a page of submissions
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ItemDistributionResponse {
+    pub distribution: Vec<(String, u64)>,
+    pub last_address: Option<Addr>, // Pass as start_after to fetch the next page
+}
+
+/// An item's tier spread (see `state::tier_spread`) with its tier -> count distribution
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ControversialItem {
+    pub name: String,
+    pub spread: Decimal,
+    pub distribution: Vec<(String, u64)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AggregatedItem {
+    pub name: String,
+    pub distribution: Vec<(String, u64)>, // Unassigned placements are not counted
+    pub consensus: Option<String>,        // Most placed tier, ties go to the higher tier
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AggregateRankingsResponse {
+    pub submissions: u64, // Submissions in this page
+    pub items: Vec<AggregatedItem>,
+    pub last_address: Option<Addr>, // Pass as start_after to fetch the next submitters
+    pub last_item: Option<String>,  // Pass as item_start_after to fetch the next page
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ControversialItemsResponse {
+    pub items: Vec<ControversialItem>,
+    pub last_address: Option<Addr>, // Pass as start_after to fetch the next page
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WeightedItem {
+    pub name: String,
+    pub average_score: Decimal,
+    pub total_score: u64,
+    pub placements: u64, // Placements in undeclared tiers are not scored
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WeightedConsensusResponse {
+    pub submissions: u64, // Submissions in this page
+    pub items: Vec<WeightedItem>,
+    pub last_address: Option<Addr>, // Pass as start_after to fetch the next page
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CategoriesResponse {
+    pub categories: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BannedAddressesResponse {
+    pub addresses: Vec<Addr>,
+}
+
+/// Both fields are None for free templates
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplateFeeResponse {
+    pub fee_token: Option<String>,
+    pub fee_amount: Option<Uint128>,
+}
+
+/// Per item comparison, see `state::compare_tierlists`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CompareTierlistsResponse {
+    pub items: Vec<ItemComparison>,
+    pub agreement: Decimal,
+}
+
+/// `score_percentage` is rounded down, and zero when neither tierlist has items
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AgreementScoreResponse {
+    pub identical_assignments: u32,
+    pub total_items: u32,
+    pub score_percentage: u8,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MostSimilarResponse {
+    pub similar: Vec<(Addr, Decimal)>,
+    pub last_address: Option<Addr>, // Pass as start_after to fetch the next page
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BootstrapResponse {
+    pub config: ConfigResponse,
+    pub templates: Vec<(u64, TierlistTemplate)>, // Featured templates, see `QueryMsg::Bootstrap`
+}
+
+/// (address, tierlist) for each pinned submission, in pinning order
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PinnedTierlistsResponse {
+    pub tierlists: Vec<(String, Tierlist)>,
+}
+
+/// (address, tierlist) for each submission in the page
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TierlistsForTemplateResponse {
+    pub tierlists: Vec<(String, Tierlist)>,
+}
+
+/// (address, tierlist, comment) for submissions that left a comment
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TierlistsWithCommentsResponse {
+    pub tierlists: Vec<(String, Tierlist, String)>,
+}
@@ -0,0 +1,212 @@
+//! Two step contract ownership with the semantics of the `cw_ownable` crate, which needs
+//! cw-storage-plus 1.x. The owner proposes a transfer that the new owner has to accept,
+//! optionally before it expires, and may renounce ownership altogether.
+
+use cosmwasm_std::{Addr, Api, Attribute, BlockInfo, DepsMut, StdResult, Storage};
+use cw_storage_plus::Item;
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::OwnershipError;
+
+/// Stored under the same key as `cw_ownable` uses
+pub const OWNERSHIP: Item<Ownership> = Item::new("ownership");
+
+/// The contract owner and any transfer waiting to be accepted
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct Ownership {
+    pub owner: Option<Addr>, // None once ownership has been renounced
+    pub pending_owner: Option<Addr>,
+    pub pending_expiry: Option<Expiration>, // None for transfers that never expire
+}
+
+impl Ownership {
+    pub fn into_attributes(self) -> Vec<Attribute> {
+        let or_none = |value: Option<String>| value.unwrap_or_else(|| "none".to_string());
+        vec![
+            Attribute::new("owner", or_none(self.owner.map(Addr::into_string))),
+            Attribute::new(
+                "pending_owner",
+                or_none(self.pending_owner.map(Addr::into_string)),
+            ),
+            Attribute::new(
+                "pending_expiry",
+                or_none(self.pending_expiry.map(|expiry| expiry.to_string())),
+            ),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    /// Owner only. Proposes `new_owner`, replacing any pending transfer
+    TransferOwnership {
+        new_owner: String,
+        expiry: Option<Expiration>,
+    },
+    /// Pending owner only, completes the transfer
+    AcceptOwnership,
+    /// Owner only. Leaves the contract without an owner for good and drops any pending
+    /// transfer
+    RenounceOwnership,
+}
+
+pub fn initialize_owner(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    owner: Option<&str>,
+) -> StdResult<Ownership> {
+    let ownership = Ownership {
+        owner: owner.map(|owner| api.addr_validate(owner)).transpose()?,
+        pending_owner: None,
+        pending_expiry: None,
+    };
+    OWNERSHIP.save(storage, &ownership)?;
+    Ok(ownership)
+}
+
+pub fn get_ownership(storage: &dyn Storage) -> StdResult<Ownership> {
+    OWNERSHIP.load(storage)
+}
+
+/// Whether `address` currently owns the contract, for checks that also admit other callers
+pub fn is_owner(storage: &dyn Storage, address: &Addr) -> StdResult<bool> {
+    Ok(OWNERSHIP.load(storage)?.owner.as_ref() == Some(address))
+}
+
+pub fn assert_owner(storage: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
+    match OWNERSHIP.load(storage)?.owner {
+        None => Err(OwnershipError::NoOwner {}),
+        Some(owner) if owner != *sender => Err(OwnershipError::NotOwner {}),
+        Some(_) => Ok(()),
+    }
+}
+
+pub fn update_ownership(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: &Addr,
+    action: Action,
+) -> Result<Ownership, OwnershipError> {
+    let mut ownership = OWNERSHIP.load(deps.storage)?;
+    match action {
+        Action::TransferOwnership { new_owner, expiry } => {
+            assert_owner(deps.storage, sender)?;
+            if matches!(&expiry, Some(expiry) if expiry.is_expired(block)) {
+                return Err(OwnershipError::InvalidExpiration {});
+            }
+            ownership.pending_owner = Some(deps.api.addr_validate(&new_owner)?);
+            ownership.pending_expiry = expiry;
+        }
+        Action::AcceptOwnership => {
+            let pending_owner = ownership
+                .pending_owner
+                .take()
+                .ok_or(OwnershipError::TransferNotFound {})?;
+            if pending_owner != *sender {
+                return Err(OwnershipError::NotPendingOwner {});
+            }
+            if matches!(&ownership.pending_expiry, Some(expiry) if expiry.is_expired(block)) {
+                return Err(OwnershipError::TransferExpired {});
+            }
+            ownership.owner = Some(pending_owner);
+            ownership.pending_expiry = None;
+        }
+        Action::RenounceOwnership => {
+            assert_owner(deps.storage, sender)?;
+            ownership = Ownership::default();
+        }
+    }
+    OWNERSHIP.save(deps.storage, &ownership)?;
+    Ok(ownership)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::OwnershipError;
+    use crate::ownership::{
+        assert_owner, get_ownership, initialize_owner, update_ownership, Action, Ownership,
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Addr;
+    use cw_utils::Expiration;
+
+    #[test]
+    fn test_transfer_ownership() {
+        let mut deps = mock_dependencies();
+        let block = mock_env().block;
+        let (alice, bob) = (Addr::unchecked("alice"), Addr::unchecked("bob"));
+        initialize_owner(&mut deps.storage, &deps.api, Some("alice")).unwrap();
+        let transfer = |expiry| Action::TransferOwnership {
+            new_owner: "bob".to_string(),
+            expiry,
+        };
+
+        let err = update_ownership(deps.as_mut(), &block, &bob, transfer(None)).unwrap_err();
+        assert_eq!(err, OwnershipError::NotOwner {});
+        let err =
+            update_ownership(deps.as_mut(), &block, &bob, Action::AcceptOwnership).unwrap_err();
+        assert_eq!(err, OwnershipError::TransferNotFound {});
+        let expired = Expiration::AtHeight(block.height);
+        let err =
+            update_ownership(deps.as_mut(), &block, &alice, transfer(Some(expired))).unwrap_err();
+        assert_eq!(err, OwnershipError::InvalidExpiration {});
+
+        // Alice stays the owner until bob accepts
+        let expiry = Expiration::AtHeight(block.height + 10);
+        update_ownership(deps.as_mut(), &block, &alice, transfer(Some(expiry))).unwrap();
+        assert_eq!(assert_owner(&deps.storage, &alice), Ok(()));
+        let err =
+            update_ownership(deps.as_mut(), &block, &alice, Action::AcceptOwnership).unwrap_err();
+        assert_eq!(err, OwnershipError::NotPendingOwner {});
+        let mut late = block.clone();
+        late.height += 10;
+        let err =
+            update_ownership(deps.as_mut(), &late, &bob, Action::AcceptOwnership).unwrap_err();
+        assert_eq!(err, OwnershipError::TransferExpired {});
+
+        let ownership =
+            update_ownership(deps.as_mut(), &block, &bob, Action::AcceptOwnership).unwrap();
+        assert_eq!(
+            ownership,
+            Ownership {
+                owner: Some(bob.clone()),
+                pending_owner: None,
+                pending_expiry: None,
+            }
+        );
+        assert_eq!(get_ownership(&deps.storage).unwrap(), ownership);
+        assert_eq!(
+            assert_owner(&deps.storage, &alice),
+            Err(OwnershipError::NotOwner {})
+        );
+    }
+
+    #[test]
+    fn test_renounce_ownership() {
+        let mut deps = mock_dependencies();
+        let block = mock_env().block;
+        let alice = Addr::unchecked("alice");
+        initialize_owner(&mut deps.storage, &deps.api, Some("alice")).unwrap();
+        let transfer = Action::TransferOwnership {
+            new_owner: "bob".to_string(),
+            expiry: None,
+        };
+        update_ownership(deps.as_mut(), &block, &alice, transfer).unwrap();
+
+        // Renouncing drops the pending transfer too
+        let ownership =
+            update_ownership(deps.as_mut(), &block, &alice, Action::RenounceOwnership).unwrap();
+        assert_eq!(ownership, Ownership::default());
+        assert_eq!(
+            assert_owner(&deps.storage, &alice),
+            Err(OwnershipError::NoOwner {})
+        );
+        let bob = Addr::unchecked("bob");
+        let err =
+            update_ownership(deps.as_mut(), &block, &bob, Action::AcceptOwnership).unwrap_err();
+        assert_eq!(err, OwnershipError::TransferNotFound {});
+    }
+}
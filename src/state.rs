@@ -1,17 +1,117 @@
+use cosmwasm_std::{
+    Addr, Binary, BlockInfo, Coin, Decimal, Empty, OverflowError, OverflowOperation, StdError,
+    StdResult, Storage, Timestamp,
+};
 use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use crate::error::{ContractError, TierlistValidationError};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
-    pub admin_address: String,
+    #[serde(default)]
+    pub require_template_tiers: bool, // Forbids templates with free-form tiers
+    #[serde(default)]
+    pub hide_creators: bool, // Blanks template creators in query responses
+    #[serde(default = "default_max_tierlist_bytes")]
+    pub max_tierlist_bytes: u64, // Largest accepted tierlist, measured as its JSON encoding
+    #[serde(default)]
+    pub min_items_per_template: Option<u32>, // Fewest items a created or edited template may have
+}
+
+/// Size limit for tierlists when none is configured
+pub const DEFAULT_MAX_TIERLIST_BYTES: u64 = 16 * 1024;
+
+fn default_max_tierlist_bytes() -> u64 {
+    DEFAULT_MAX_TIERLIST_BYTES
 }
 
-/// Tierlist item having a name and an optional image
+fn default_true() -> bool {
+    true
+}
+
+/// Tierlist item having a name, an optional image and a display order
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TierlistItem {
     pub name: String,
     pub image_url: Option<String>,
+    #[serde(default)]
+    pub order: u32,
+    /// (locale, name) display names, not part of the item's identity when validating
+    #[serde(default)]
+    pub alt_names: Option<Vec<(String, String)>>,
+}
+
+/// Most items a template may hold
+pub const MAX_TEMPLATE_ITEMS: usize = 100;
+
+/// Most alternative names an item may carry
+pub const MAX_ALT_NAMES: usize = 5;
+
+/// Longest save idempotency key in bytes
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 64;
+
+/// Most collaborators a template may be created with
+pub const MAX_INITIAL_COLLABORATORS: usize = 5;
+
+/// Longest alternative name in bytes
+pub const MAX_ALT_NAME_LENGTH: usize = 64;
+
+/// A tier declared by a template. Assignments are validated by name only, the color and
+/// emoji are display hints. Plain names, as tiers used to be stored, still deserialize.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(from = "TierDefRepr")]
+pub struct TierDef {
+    pub name: String,
+    pub color: Option<String>, // "#RRGGBB"
+    pub emoji: Option<String>,
+}
+
+/// Longest tier emoji in bytes
+pub const MAX_TIER_EMOJI_BYTES: usize = 8;
+
+/// Fewest tiers a template may be left with when removing a tier definition
+pub const MIN_TIER_DEFINITIONS: usize = 2;
+
+/// Most tiers a template may be built up to by adding tier definitions
+pub const MAX_TIER_DEFINITIONS: usize = 10;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TierDefRepr {
+    Name(String),
+    Def {
+        name: String,
+        color: Option<String>,
+        emoji: Option<String>,
+    },
+}
+
+impl From<TierDefRepr> for TierDef {
+    fn from(repr: TierDefRepr) -> Self {
+        match repr {
+            TierDefRepr::Name(name) => TierDef {
+                name,
+                color: None,
+                emoji: None,
+            },
+            TierDefRepr::Def { name, color, emoji } => TierDef { name, color, emoji },
+        }
+    }
+}
+
+impl From<&str> for TierDef {
+    fn from(name: &str) -> Self {
+        TierDef {
+            name: name.to_string(),
+            color: None,
+            emoji: None,
+        }
+    }
 }
 
 /// Tierlist template AKA providing the name and the items the people tier.
@@ -20,7 +120,183 @@ pub struct TierlistTemplate {
     pub id: u64,
     pub title: String,
     pub items: Vec<TierlistItem>,
-    pub creator: String,
+    pub creator: Addr,
+    pub tier_definitions: Option<Vec<TierDef>>, // None allows any tier label
+    pub max_submissions: Option<u32>,           // None allows unlimited submissions
+    #[serde(default = "default_true")]
+    pub submissions_open: bool,
+    #[serde(default)]
+    pub content_hash: Binary, // See `compute_content_hash`, empty for templates stored before it
+    #[serde(default)]
+    pub sealed_at: Option<(u64, Timestamp)>, // Block height and time the template was sealed
+    #[serde(default)]
+    pub expires_at: Option<u64>, // Last block height accepting saves, None never expires
+    #[serde(default)]
+    pub category: Option<String>, // One of `CATEGORIES`
+    #[serde(default)]
+    pub language: Option<String>, // Lowercase language tag such as "en" or "pt-br"
+    #[serde(default)]
+    pub archived: bool, // Archived templates stay readable but take no new submissions
+    #[serde(default)]
+    pub forked_from: Option<u64>, // Template this one was cloned from
+    #[serde(default)]
+    pub source: Option<(String, u64)>, // Contract and template id the items were copied from
+    #[serde(default)]
+    pub created_at: Option<Timestamp>, // None for templates created before this was tracked
+    #[serde(default)]
+    pub strict_uniqueness: bool, // Items are unique by (name, image url) rather than by name
+    #[serde(default)]
+    pub visibility: TemplateVisibility,
+}
+
+/// Whether a template shows up in the public listings
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateVisibility {
+    #[default]
+    Public,
+    /// Left out of the public listings but loads and accepts saves by id as usual
+    Unlisted,
+}
+
+impl TierlistTemplate {
+    /// Position of the tier in the declared order, 0 being the highest. None for free-form
+    /// templates and tiers the template does not declare.
+    pub fn tier_rank(&self, tier: &str) -> Option<usize> {
+        self.tier_definitions
+            .as_ref()?
+            .iter()
+            .position(|t| t.name == tier)
+    }
+
+    /// Score of a declared tier, the top tier scores the number of declared tiers and the
+    /// bottom tier scores 1
+    pub fn tier_score(&self, tier: &str) -> Option<u64> {
+        let tiers = self.tier_definitions.as_ref()?.len();
+        self.tier_rank(tier).map(|rank| (tiers - rank) as u64)
+    }
+
+    /// Orders higher tiers first: declared tiers by rank, then undeclared tiers by name
+    pub fn compare_tiers(&self, a: &str, b: &str) -> Ordering {
+        match (self.tier_rank(a), self.tier_rank(b)) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.cmp(b),
+        }
+    }
+
+    /// Anonymous templates have no creator and are managed by the admin alone
+    pub fn is_anonymous(&self) -> bool {
+        self.creator.as_str().is_empty()
+    }
+
+    /// Expired templates no longer accept saves
+    pub fn is_expired(&self, height: u64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if height > expires_at)
+    }
+
+    /// Sealed templates no longer accept any writes to their tierlists
+    pub fn is_sealed(&self) -> bool {
+        self.sealed_at.is_some()
+    }
+
+    /// Sorts the items by their display order, ties keep their stored order
+    pub fn sort_items(&mut self) {
+        self.items.sort_by_key(|i| i.order);
+    }
+
+    /// SHA-256 of a canonical encoding of the title, items and tier definitions.
+    ///
+    /// Items are hashed sorted by name, so the order they were submitted in does not
+    /// matter but each item's display `order` does, and their alt names are hashed
+    /// sorted by locale. Tier definitions are hashed in
+    /// their declared order since that order ranks the tiers, each with its color and
+    /// emoji so restyling a tier changes the hash. Strings are length
+    /// prefixed, lists are count prefixed and options carry a 0/1 tag, all as big endian
+    /// u32s, so the same content always yields the same hash.
+    pub fn compute_content_hash(&self) -> Binary {
+        let mut items: Vec<_> = self.items.iter().collect();
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut hasher = Sha256::new();
+        hash_str(&mut hasher, &self.title);
+        hasher.update((items.len() as u32).to_be_bytes());
+        for item in items {
+            hash_str(&mut hasher, &item.name);
+            hash_option(&mut hasher, &item.image_url);
+            hasher.update(item.order.to_be_bytes());
+            match &item.alt_names {
+                None => hasher.update(0u32.to_be_bytes()),
+                Some(alt_names) => {
+                    let mut alt_names: Vec<_> = alt_names.iter().collect();
+                    alt_names.sort();
+                    hasher.update(1u32.to_be_bytes());
+                    hasher.update((alt_names.len() as u32).to_be_bytes());
+                    for (locale, name) in alt_names {
+                        hash_str(&mut hasher, locale);
+                        hash_str(&mut hasher, name);
+                    }
+                }
+            }
+        }
+        match &self.tier_definitions {
+            None => hasher.update(0u32.to_be_bytes()),
+            Some(tiers) => {
+                hasher.update(1u32.to_be_bytes());
+                hasher.update((tiers.len() as u32).to_be_bytes());
+                for tier in tiers {
+                    hash_str(&mut hasher, &tier.name);
+                    hash_option(&mut hasher, &tier.color);
+                    hash_option(&mut hasher, &tier.emoji);
+                }
+            }
+        }
+        Binary::from(hasher.finalize().as_slice())
+    }
+
+    /// Recomputes the stored content hash, call after any content change
+    pub fn refresh_content_hash(&mut self) {
+        self.content_hash = self.compute_content_hash();
+    }
+
+    /// The stored content hash, computed on the fly for templates stored without one
+    pub fn effective_content_hash(&self) -> Binary {
+        if self.content_hash.is_empty() {
+            self.compute_content_hash()
+        } else {
+            self.content_hash.clone()
+        }
+    }
+}
+
+fn hash_str(hasher: &mut Sha256, value: &str) {
+    hasher.update((value.len() as u32).to_be_bytes());
+    hasher.update(value.as_bytes());
+}
+
+fn hash_option(hasher: &mut Sha256, value: &Option<String>) {
+    match value {
+        None => hasher.update(0u32.to_be_bytes()),
+        Some(value) => {
+            hasher.update(1u32.to_be_bytes());
+            hash_str(hasher, value);
+        }
+    }
+}
+
+/// Number of revisions kept in a tierlist's metadata
+pub const MAX_REVISIONS: usize = 20;
+
+/// Number of full tierlist snapshots kept in `TIERLIST_HISTORY`
+pub const MAX_HISTORY_SNAPSHOTS: u64 = 5;
+
+/// When a tierlist was saved
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RevisionMeta {
+    pub revision: u64,
+    pub height: u64,
+    pub time: Timestamp,
 }
 
 /// A tierlist a user is completing
@@ -28,9 +304,37 @@ pub struct TierlistTemplate {
 pub struct Tierlist {
     pub template_id: u64,
     pub items_to_tiers: Vec<(TierlistItem, String)>, // Unassigned items point to a blank string
+    #[serde(default)]
+    pub revisions: Vec<RevisionMeta>, // Managed by the contract, oldest first
+    #[serde(default)]
+    pub locked: bool,           // Locked tierlists reject further saves, managed by the contract
 }
 
 impl Tierlist {
+    /// Hex SHA-256 of the item to tier assignments, sorted by item name so that the
+    /// submission order does not change it. Names and tiers are length prefixed as in
+    /// `TierlistTemplate::compute_content_hash`.
+    pub fn fingerprint(&self) -> String {
+        let mut assignments: Vec<_> = self
+            .items_to_tiers
+            .iter()
+            .map(|(item, tier)| (item.name.as_str(), tier.as_str()))
+            .collect();
+        assignments.sort_unstable();
+
+        let mut hasher = Sha256::new();
+        hasher.update((assignments.len() as u32).to_be_bytes());
+        for (name, tier) in assignments {
+            hash_str(&mut hasher, name);
+            hash_str(&mut hasher, tier);
+        }
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
     pub fn from_template(template: TierlistTemplate) -> Tierlist {
         let mut items_to_tiers: Vec<(TierlistItem, String)> = vec![];
         for item in template.items {
@@ -39,23 +343,129 @@ impl Tierlist {
         Tierlist {
             items_to_tiers,
             template_id: template.id,
+            revisions: vec![],
+            locked: false,
+        }
+    }
+
+    /// Appends a revision for a save at the given block, dropping the oldest beyond
+    /// `MAX_REVISIONS`. Revision numbers come from `TIERLIST_REVISION_COUNT`.
+    pub fn record_revision(&mut self, revision: u64, block: &BlockInfo) {
+        self.revisions.push(RevisionMeta {
+            revision,
+            height: block.height,
+            time: block.time,
+        });
+        if self.revisions.len() > MAX_REVISIONS {
+            self.revisions.remove(0);
         }
     }
 
     pub fn validate_against_template(self, template: TierlistTemplate) -> bool {
+        self.validate(&template).is_ok()
+    }
+
+    /// Checks the tierlist holds exactly the template's items, matched by name and image.
+    /// Display order is not part of an item's identity.
+    pub fn validate(&self, template: &TierlistTemplate) -> Result<(), TierlistValidationError> {
         if self.template_id != template.id {
-            return false;
+            return Err(TierlistValidationError::WrongTemplate {
+                expected: template.id,
+                actual: self.template_id,
+            });
         }
 
-        let mut items: Vec<_> = self
-            .items_to_tiers
+        let items = images_by_name(self.items_to_tiers.iter().map(|i| &i.0));
+        let template_items = images_by_name(template.items.iter());
+
+        for (name, urls) in &items {
+            let name = name.to_string();
+            match template_items.get(name.as_str()) {
+                None => return Err(TierlistValidationError::UnknownItem { name }),
+                Some(expected) if urls.len() > expected.len() => {
+                    return Err(TierlistValidationError::DuplicateItem { name })
+                }
+                Some(expected) if urls.len() == expected.len() && urls != expected => {
+                    return Err(TierlistValidationError::ItemMismatch { name })
+                }
+                _ => {}
+            }
+        }
+        for (name, urls) in &template_items {
+            if items.get(name).map_or(0, |u| u.len()) < urls.len() {
+                return Err(TierlistValidationError::MissingItem {
+                    name: name.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Orders the assignments like the template's items are displayed, items the template
+    /// no longer has go last in their saved order
+    pub fn sort_by_template(&mut self, template: &TierlistTemplate) {
+        let mut items: Vec<_> = template.items.iter().collect();
+        items.sort_by_key(|i| i.order);
+        self.items_to_tiers.sort_by_key(|(item, _)| {
+            items
+                .iter()
+                .position(|i| i.name == item.name)
+                .unwrap_or(items.len())
+        });
+    }
+
+    /// Number of items placed in a tier
+    pub fn assigned_count(&self) -> usize {
+        self.items_to_tiers
+            .iter()
+            .filter(|(_, tier)| !tier.is_empty())
+            .count()
+    }
+
+    /// Returns the first assigned tier label not declared by the template, if any
+    pub fn invalid_tier_label(&self, template: &TierlistTemplate) -> Option<String> {
+        let tiers = template.tier_definitions.as_ref()?;
+        let names: Vec<String> = tiers.iter().map(|t| t.name.clone()).collect();
+        self.invalid_label_among(&names)
+    }
+
+    /// Returns the first assigned tier label that is not one of `tiers`, if any
+    pub fn invalid_label_among(&self, tiers: &[String]) -> Option<String> {
+        self.items_to_tiers
+            .iter()
+            .map(|i| &i.1)
+            .find(|tier| !tier.is_empty() && !tiers.contains(tier))
+            .cloned()
+    }
+
+    /// Reconciles with the current template: new items are added unassigned, removed items
+    /// are dropped and tiers the template no longer defines are cleared
+    pub fn sync_with_template(&mut self, template: &TierlistTemplate) {
+        let items_to_tiers = template
+            .items
             .iter()
-            .map(|i| -> TierlistItem { i.clone().0 })
+            .map(|item| {
+                let tier = self
+                    .items_to_tiers
+                    .iter()
+                    .find(|i| i.0.name == item.name)
+                    .map(|i| i.1.clone())
+                    .filter(|tier| match &template.tier_definitions {
+                        Some(tiers) => tiers.iter().any(|t| t.name == *tier),
+                        None => true,
+                    })
+                    .unwrap_or_default();
+                (item.clone(), tier)
+            })
             .collect();
-        let mut template_items = template.items;
-        items.sort_by(|a, b| a.name.cmp(&b.name));
-        template_items.sort_by(|a, b| a.name.cmp(&b.name));
-        items == template_items
+        self.items_to_tiers = items_to_tiers;
+    }
+
+    /// Blanks every tier label, keeping the items
+    pub fn clear_tiers(&mut self) {
+        for (_, tier) in self.items_to_tiers.iter_mut() {
+            tier.clear();
+        }
     }
 
     pub fn assign(&mut self, item: TierlistItem, tier: String) {
@@ -75,6 +485,37 @@ impl Tierlist {
         self.items_to_tiers = it;
     }
 
+    /// Groups items by tier. Declared tiers come first in declaration order, including empty
+    /// ones, any other tiers follow alphabetically and unassigned items come last under "".
+    pub fn group_by_tier(
+        &self,
+        tier_definitions: &Option<Vec<TierDef>>,
+    ) -> Vec<(TierDef, Vec<TierlistItem>)> {
+        let declared = tier_definitions.as_deref().unwrap_or_default();
+        let mut groups: Vec<(TierDef, Vec<TierlistItem>)> =
+            declared.iter().map(|tier| (tier.clone(), vec![])).collect();
+        let mut undeclared: BTreeMap<&str, Vec<TierlistItem>> = BTreeMap::new();
+        let mut unassigned = vec![];
+        for (item, tier) in &self.items_to_tiers {
+            if tier.is_empty() {
+                unassigned.push(item.clone());
+            } else if let Some(position) = declared.iter().position(|t| t.name == *tier) {
+                groups[position].1.push(item.clone());
+            } else {
+                undeclared.entry(tier).or_default().push(item.clone());
+            }
+        }
+        groups.extend(
+            undeclared
+                .into_iter()
+                .map(|(tier, items)| (TierDef::from(tier), items)),
+        );
+        if !unassigned.is_empty() {
+            groups.push((TierDef::from(""), unassigned));
+        }
+        groups
+    }
+
     pub fn get_tier(&self, item: TierlistItem) -> String {
         let idx = self
             .items_to_tiers
@@ -85,21 +526,328 @@ impl Tierlist {
     }
 }
 
+/// Groups item images by name, sorted so equal multisets compare equal
+/// Items are identified by name and image only, so alternative names never affect validation
+fn images_by_name<'a>(
+    items: impl Iterator<Item = &'a TierlistItem>,
+) -> BTreeMap<&'a str, Vec<&'a Option<String>>> {
+    let mut images: BTreeMap<&str, Vec<&Option<String>>> = BTreeMap::new();
+    for item in items {
+        images.entry(&item.name).or_default().push(&item.image_url);
+    }
+    for urls in images.values_mut() {
+        urls.sort();
+    }
+    images
+}
+
+/// How divided the submissions are over an item's tier, from zero when every submission
+/// agrees on one tier towards one as they spread evenly over many tiers.
+///
+/// Computed as `1 - largest_count / total` over the (tier, count) distribution.
+pub fn tier_spread(distribution: &[(String, u64)]) -> Decimal {
+    let total: u64 = distribution.iter().map(|(_, count)| count).sum();
+    let largest = distribution
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or_default();
+    if total == 0 {
+        return Decimal::zero();
+    }
+    Decimal::one() - Decimal::from_ratio(largest, total)
+}
+
+/// How two submissions placed one item
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ItemComparison {
+    pub item: String,
+    pub tier_a: Option<String>, // None when the item is missing from the tierlist
+    pub tier_b: Option<String>,
+    pub agree: bool,
+    pub distance: Option<u32>, // Tiers apart, only when both tiers are declared on the template
+}
+
+/// Compares two tierlists item by item, returning each item's comparison followed by the
+/// fraction of items both placed in the same tier. Items are listed in `a`'s order, then
+/// any only present in `b`.
+pub fn compare_tierlists(
+    a: &Tierlist,
+    b: &Tierlist,
+    template: &TierlistTemplate,
+) -> (Vec<ItemComparison>, Decimal) {
+    let mut names: Vec<&str> = a.items_to_tiers.iter().map(|i| i.0.name.as_str()).collect();
+    for (item, _) in &b.items_to_tiers {
+        if !names.contains(&item.name.as_str()) {
+            names.push(&item.name);
+        }
+    }
+
+    let tier_of = |tierlist: &Tierlist, name: &str| {
+        tierlist
+            .items_to_tiers
+            .iter()
+            .find(|i| i.0.name == name)
+            .map(|i| i.1.clone())
+    };
+    let rank_of = |tier: &Option<String>| template.tier_rank(tier.as_ref()?);
+    let comparisons: Vec<_> = names
+        .into_iter()
+        .map(|name| {
+            let tier_a = tier_of(a, name);
+            let tier_b = tier_of(b, name);
+            let distance = match (rank_of(&tier_a), rank_of(&tier_b)) {
+                (Some(x), Some(y)) => Some((x as i64 - y as i64).unsigned_abs() as u32),
+                _ => None,
+            };
+            ItemComparison {
+                item: name.to_string(),
+                agree: tier_a.is_some() && tier_a == tier_b,
+                tier_a,
+                tier_b,
+                distance,
+            }
+        })
+        .collect();
+
+    let agreed = comparisons.iter().filter(|c| c.agree).count() as u64;
+    let agreement = if comparisons.is_empty() {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(agreed, comparisons.len() as u64)
+    };
+    (comparisons, agreement)
+}
+
 /// General config
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// The admin field configs stored before ownership moved to `ownership::OWNERSHIP`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyConfig {
+    pub admin_address: String,
+}
+
+/// Read once by `migrate` to seed the owner, shares the key of `CONFIG`
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
+/// Tiers labels are checked against for templates that declare none, unset by default
+pub const DEFAULT_TIERS: Item<Vec<String>> = Item::new("default_tiers");
+
+/// Most default tiers the admin may configure
+pub const MAX_DEFAULT_TIERS: usize = 10;
+
+/// Longest default tier name in characters
+pub const MAX_DEFAULT_TIER_LENGTH: usize = 20;
+
+/// Progress of the address normalization migration, templates are walked before tierlists
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct MigrationStatus {
+    pub template_cursor: Option<u64>, // Last template id processed
+    pub tierlist_cursor: Option<(Addr, u64)>, // Last tierlist key processed
+    pub templates_done: bool,
+    pub tierlists_done: bool,
+    pub rewritten: u64, // Records moved to their normalized address
+    pub skipped: u64,   // Legacy tierlists left in place as the normalized key was taken
+}
+
+pub const MIGRATION_STATUS: Item<MigrationStatus> = Item::new("migration_status");
+
 /// ID helper for tierlists
 pub const NEXT_ID: Item<u64> = Item::new("next_id");
 
+/// Takes the next template id, erroring rather than wrapping once ids run out
+pub fn allocate_template_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let id = NEXT_ID.may_load(storage)?.unwrap_or_default();
+    let next = id
+        .checked_add(1)
+        .ok_or_else(|| StdError::overflow(OverflowError::new(OverflowOperation::Add, id, 1)))?;
+    NEXT_ID.save(storage, &next)?;
+    Ok(id)
+}
+
+/// Loads a template, telling ids that were never assigned apart from deleted templates
+pub fn load_template_or_err(
+    storage: &dyn Storage,
+    id: u64,
+) -> Result<TierlistTemplate, ContractError> {
+    match TIERLIST_TEMPLATES.may_load(storage, id)? {
+        Some(template) => Ok(template),
+        None if id < NEXT_ID.may_load(storage)?.unwrap_or_default() => {
+            Err(ContractError::TemplateNotFound { id })
+        }
+        None => Err(ContractError::InvalidTemplateId { id }),
+    }
+}
+
+/// Loads an address's submission, erroring with `TierlistNotFound` when there is none
+pub fn load_tierlist_or_err(
+    storage: &dyn Storage,
+    address: &Addr,
+    template_id: u64,
+) -> Result<Tierlist, ContractError> {
+    TIERLISTS
+        .may_load(storage, (address, template_id))?
+        .ok_or_else(|| ContractError::TierlistNotFound {
+            address: address.to_string(),
+            template_id,
+        })
+}
+
 /// Allows people to make templates for others to use.
-pub const TIERLIST_TEMPLATES: Map<u64, TierlistTemplate> = Map::new("tierlist_templates");
+pub const TIERLIST_TEMPLATES: Map<u64, TierlistTemplate> =
+    Map::new(raw::TIERLIST_TEMPLATES_NAMESPACE);
+
+/// Categories seeded at instantiation, the admin may add and remove categories afterwards
+pub const DEFAULT_CATEGORIES: [&str; 5] = ["Games", "Anime", "Food", "Sports", "Other"];
+
+/// Categories templates may be filed under
+pub const CATEGORIES: Item<Vec<String>> = Item::new("categories");
+
+/// Index of templates by category
+pub const TEMPLATES_BY_CATEGORY: Map<(&str, u64), Empty> = Map::new("templates_by_category");
+
+/// Index of templates by language, templates without a language are not indexed
+pub const TEMPLATES_BY_LANGUAGE: Map<(&str, u64), Empty> = Map::new("templates_by_language");
+
+/// Template id by slug, slugs are unique across templates
+pub const TEMPLATES_BY_SLUG: Map<&str, u64> = Map::new("templates_by_slug");
+
+/// Slug of each template that has one, the reverse of `TEMPLATES_BY_SLUG`
+pub const TEMPLATE_SLUGS: Map<u64, String> = Map::new("template_slugs");
+
+pub const MAX_SLUG_LENGTH: usize = 64;
+
+/// Index of archived templates
+pub const ARCHIVED_TEMPLATES: Map<u64, Empty> = Map::new("archived_templates");
+
+/// Fee paid to a template's creator on every tierlist save
+pub const TEMPLATE_FEES: Map<u64, Coin> = Map::new("template_fees");
+
+/// Pointer to a template hosted off-chain, such as on IPFS or Arweave
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ExternalTemplateRef {
+    pub external_id: String,
+    pub metadata_url: String,
+    pub registered_by: Addr,
+    pub created_at: Timestamp,
+}
+
+/// Longest external template id in bytes
+pub const MAX_EXTERNAL_ID_LENGTH: usize = 64;
+
+/// Longest external template metadata url in bytes
+pub const MAX_METADATA_URL_LENGTH: usize = 256;
+
+/// Url schemes accepted for external template metadata
+pub const METADATA_URL_SCHEMES: [&str; 4] = ["https://", "http://", "ipfs://", "ar://"];
+
+/// Off-chain templates keyed by their external id, first registration wins
+pub const EXTERNAL_TEMPLATES: Map<&str, ExternalTemplateRef> = Map::new("external_templates");
+
+/// Next nonce expected in each owner's save permit
+pub const NONCES: Map<&str, u64> = Map::new("nonces");
+
+/// Addresses that may edit a template's content besides its creator, keyed by
+/// (template id, address)
+pub const TEMPLATE_COLLABORATORS: Map<(u64, &Addr), Empty> = Map::new("template_collaborators");
+
+/// Reverse of `TEMPLATE_COLLABORATORS`, keyed by (address, template id)
+pub const USER_COLLABORATING: Map<(&Addr, u64), Empty> = Map::new("user_collaborating");
+
+/// Index of forks by the template they were cloned from
+pub const TEMPLATE_FORKS: Map<(u64, u64), Empty> = Map::new("template_forks");
+
+/// Index of templates by creation time in seconds
+pub const TEMPLATES_BY_CREATED_AT: Map<(u64, u64), Empty> = Map::new("templates_by_created_at");
+
+/// Index of templates by the names of their items
+pub const TEMPLATES_BY_ITEM: Map<(&str, u64), Empty> = Map::new("templates_by_item");
+
+/// Index of templates by creator
+pub const TEMPLATES_BY_CREATOR: Map<(&Addr, u64), Empty> = Map::new("templates_by_creator");
 
 /// Peoples in progress and complete tierlists
-pub const TIERLISTS: Map<(String, u64), Tierlist> = Map::new("tierlists");
+pub const TIERLISTS: Map<(&Addr, u64), Tierlist> = Map::new(raw::TIERLISTS_NAMESPACE);
+
+/// Most tierlists a single `SaveTierlists` message may carry
+pub const MAX_BATCH_SAVES: usize = 10;
+
+/// Maximum length of a tierlist comment in characters
+pub const MAX_COMMENT_LENGTH: usize = 280;
+
+/// Comments left alongside a tierlist, keyed like `TIERLISTS`
+pub const TIERLIST_COMMENTS: Map<(&Addr, u64), String> = Map::new("tierlist_comments");
+
+/// Addresses the admin has barred from creating templates and saving tierlists
+pub const BANNED_ADDRESSES: Map<&Addr, Empty> = Map::new("banned_addresses");
+
+/// Maximum number of tierlists the admin may pin per template
+pub const MAX_PINNED_TIERLISTS: usize = 5;
+
+/// Tierlists highlighted by the admin, keyed by template id
+pub const PINNED_TIERLISTS: Map<u64, Vec<Addr>> = Map::new("pinned_tierlists");
+
+/// Idempotency key of each tierlist's last save, keyed by (address, template id). Only the
+/// latest key is kept so stored keys are bounded by the number of tierlists
+pub const SAVE_IDEMPOTENCY_KEYS: Map<(&Addr, u64), String> = Map::new("save_idempotency_keys");
+
+/// Snapshots of the latest overwritten tierlist revisions, keyed by (address, template id,
+/// revision). The current revision is only snapshotted once a save replaces it.
+pub const TIERLIST_HISTORY: Map<(&Addr, u64, u64), Tierlist> = Map::new("tierlist_history");
+
+/// Latest revision number of each tierlist, keyed by (address, template id). It is kept when
+/// the tierlist is removed so revision numbers are never reused.
+pub const TIERLIST_REVISION_COUNT: Map<(&Addr, u64), u64> = Map::new("tierlist_revision_count");
+
+/// Number of tierlists submitted per template. Only first saves count and deletions are
+/// subtracted, so this is the number of unique participants.
+pub const TEMPLATE_TIERLIST_COUNT: Map<u64, u64> = Map::new("template_tierlist_count");
+
+/// Index of tierlists by template, allows finding every submission for a template
+pub const TIERLISTS_BY_TEMPLATE_INDEX: Map<(u64, &Addr), Empty> =
+    Map::new("tierlists_by_template_index");
+
+/// Tierlists their owner has published, a subset of `TIERLISTS_BY_TEMPLATE_INDEX`
+pub const PUBLIC_TIERLISTS: Map<(u64, &Addr), Empty> = Map::new("public_tierlists");
+
+/// Index of tierlists with a comment by template, a subset of `TIERLISTS_BY_TEMPLATE_INDEX`
+pub const TIERLISTS_WITH_COMMENTS_INDEX: Map<(u64, &Addr), Empty> =
+    Map::new("tierlists_with_comments_index");
+
+/// Stable storage layout for contracts reading our state with `WasmQuery::Raw`.
+///
+/// Values are stored as JSON, so the bytes under these keys decode straight into
+/// `TierlistTemplate` and `Tierlist`.
+pub mod raw {
+    use super::{TIERLISTS, TIERLIST_TEMPLATES};
+    use cosmwasm_std::Addr;
+
+    pub const TIERLIST_TEMPLATES_NAMESPACE: &str = "tierlist_templates";
+    pub const TIERLISTS_NAMESPACE: &str = "tierlists";
+
+    /// Raw key of the template with the given id
+    pub fn template_key(id: u64) -> Vec<u8> {
+        TIERLIST_TEMPLATES.key(id).to_vec()
+    }
+
+    /// Raw key of an address's tierlist for a template
+    pub fn tierlist_key(address: &Addr, template_id: u64) -> Vec<u8> {
+        TIERLISTS.key((address, template_id)).to_vec()
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::state::{Tierlist, TierlistItem, TierlistTemplate};
+    use crate::error::{ContractError, TierlistValidationError};
+    use crate::state::{
+        allocate_template_id, compare_tierlists, load_template_or_err, load_tierlist_or_err, raw,
+        tier_spread, TemplateVisibility, TierDef, Tierlist, TierlistItem, TierlistTemplate,
+        MAX_REVISIONS, NEXT_ID, TIERLISTS, TIERLIST_TEMPLATES,
+    };
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::{from_slice, Addr, Binary, Decimal, StdError};
+    use std::cmp::Ordering;
 
     pub const ADDR1: &str = "addr1";
 
@@ -108,14 +856,20 @@ mod tests {
             TierlistItem {
                 name: "A".to_string(),
                 image_url: None,
+                order: 0,
+                alt_names: None,
             },
             TierlistItem {
                 name: "B".to_string(),
                 image_url: None,
+                order: 0,
+                alt_names: None,
             },
             TierlistItem {
                 name: "C".to_string(),
                 image_url: None,
+                order: 0,
+                alt_names: None,
             },
         ]
     }
@@ -126,6 +880,8 @@ mod tests {
                 TierlistItem {
                     name: "A".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 "".to_string(),
             ),
@@ -133,6 +889,8 @@ mod tests {
                 TierlistItem {
                     name: "B".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 "".to_string(),
             ),
@@ -140,6 +898,8 @@ mod tests {
                 TierlistItem {
                     name: "C".to_string(),
                     image_url: None,
+                    order: 0,
+                    alt_names: None,
                 },
                 "".to_string(),
             ),
@@ -150,8 +910,22 @@ mod tests {
         TierlistTemplate {
             id: 0,
             title: "Some tierlist".to_string(),
+            tier_definitions: None,
+            max_submissions: None,
             items: make_items(),
-            creator: ADDR1.to_string(),
+            creator: Addr::unchecked(ADDR1),
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
         }
     }
 
@@ -170,9 +944,16 @@ mod tests {
         let populated = Tierlist::from_template(template.clone());
         assert!(populated.validate_against_template(template.clone()));
 
+        // Item order is ignored
+        let mut reordered = Tierlist::from_template(template.clone());
+        reordered.items_to_tiers[0].0.order = 5;
+        assert!(reordered.validate_against_template(template.clone()));
+
         // Mismatched IDs
         let corrupted = Tierlist {
             template_id: 1,
+            revisions: vec![],
+            locked: false,
             items_to_tiers: make_tiered_items(),
         };
         assert!(!corrupted.validate_against_template(template.clone()));
@@ -180,11 +961,15 @@ mod tests {
         // Item missing
         let corrupted = Tierlist {
             template_id: 1,
+            revisions: vec![],
+            locked: false,
             items_to_tiers: vec![
                 (
                     TierlistItem {
                         name: "A".to_string(),
                         image_url: None,
+                        order: 0,
+                        alt_names: None,
                     },
                     "".to_string(),
                 ),
@@ -192,6 +977,8 @@ mod tests {
                     TierlistItem {
                         name: "B".to_string(),
                         image_url: None,
+                        order: 0,
+                        alt_names: None,
                     },
                     "".to_string(),
                 ),
@@ -202,11 +989,15 @@ mod tests {
         // Item added
         let corrupted = Tierlist {
             template_id: 1,
+            revisions: vec![],
+            locked: false,
             items_to_tiers: vec![
                 (
                     TierlistItem {
                         name: "A".to_string(),
                         image_url: None,
+                        order: 0,
+                        alt_names: None,
                     },
                     "".to_string(),
                 ),
@@ -214,6 +1005,8 @@ mod tests {
                     TierlistItem {
                         name: "B".to_string(),
                         image_url: None,
+                        order: 0,
+                        alt_names: None,
                     },
                     "".to_string(),
                 ),
@@ -221,6 +1014,8 @@ mod tests {
                     TierlistItem {
                         name: "C".to_string(),
                         image_url: None,
+                        order: 0,
+                        alt_names: None,
                     },
                     "".to_string(),
                 ),
@@ -228,6 +1023,8 @@ mod tests {
                     TierlistItem {
                         name: "D".to_string(),
                         image_url: None,
+                        order: 0,
+                        alt_names: None,
                     },
                     "".to_string(),
                 ),
@@ -243,6 +1040,8 @@ mod tests {
         let item = TierlistItem {
             name: "A".to_string(),
             image_url: None,
+            order: 0,
+            alt_names: None,
         };
 
         // Blank for no tier
@@ -260,4 +1059,411 @@ mod tests {
         populated.assign(item.clone(), "".to_string());
         assert_eq!(populated.get_tier(item), "".to_string());
     }
+
+    #[test]
+    fn test_invalid_tier_label() {
+        let mut template = make_template();
+        let mut populated = Tierlist::from_template(template.clone());
+        populated.assign(
+            TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            },
+            "Z".to_string(),
+        );
+
+        // Free-form tiers accept anything
+        assert_eq!(populated.invalid_tier_label(&template), None);
+
+        // Undeclared tier is reported
+        template.tier_definitions = Some(vec!["S".into(), "A".into()]);
+        assert_eq!(
+            populated.invalid_tier_label(&template),
+            Some("Z".to_string())
+        );
+
+        // Declared and blank tiers are fine
+        populated.assign(
+            TierlistItem {
+                name: "A".to_string(),
+                image_url: None,
+                order: 0,
+                alt_names: None,
+            },
+            "S".to_string(),
+        );
+        assert_eq!(populated.invalid_tier_label(&template), None);
+    }
+
+    fn to_hex(hash: &Binary) -> String {
+        hash.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_content_hash() {
+        // Pinned so accidental canonicalization changes are caught
+        let mut template = make_template();
+        assert_eq!(
+            to_hex(&template.compute_content_hash()),
+            "edfbf4bd2fb91d20aad9d3b6697a873c99992e19d101c816bde35cec68528a26"
+        );
+
+        template.items[0].image_url = Some("https://example.com/a.png".to_string());
+        template.tier_definitions = Some(vec!["S".into(), "A".into()]);
+        let hash = template.compute_content_hash();
+        assert_eq!(
+            to_hex(&hash),
+            "d7cdbd08648a99275c65d94a4d18a12295cedb82b0a5e5f2b64f38bb0beda427"
+        );
+
+        // Storage order of items does not matter
+        template.items.reverse();
+        assert_eq!(template.compute_content_hash(), hash);
+
+        // Display order and tier order do
+        template.items[0].order = 1;
+        assert_ne!(template.compute_content_hash(), hash);
+        template.items[0].order = 0;
+        template.tier_definitions = Some(vec!["A".into(), "S".into()]);
+        assert_ne!(template.compute_content_hash(), hash);
+
+        // Only content is hashed
+        template.tier_definitions = Some(vec!["S".into(), "A".into()]);
+        template.id = 7;
+        template.max_submissions = Some(3);
+        assert_eq!(template.compute_content_hash(), hash);
+
+        // Tier styling is content
+        template.tier_definitions = Some(vec![
+            TierDef {
+                name: "S".to_string(),
+                color: Some("#FF0000".to_string()),
+                emoji: None,
+            },
+            "A".into(),
+        ]);
+        let colored = template.compute_content_hash();
+        assert_eq!(
+            to_hex(&colored),
+            "632434959aeff923385caf6a7b6e61073f1710c185edd0543c9f6692fd55a9b1"
+        );
+        template.tier_definitions.as_mut().unwrap()[0].emoji = Some("🔥".to_string());
+        assert_ne!(template.compute_content_hash(), colored);
+
+        // Alt names are content, hashed in locale order
+        let mut template = make_template();
+        template.items[0].alt_names = Some(vec![
+            ("ko".to_string(), "에이".to_string()),
+            ("en".to_string(), "Ay".to_string()),
+        ]);
+        let hash = template.compute_content_hash();
+        assert_eq!(
+            to_hex(&hash),
+            "df7fff12e35956451a18e0917d5474b68ae9a61702f1f0edf0bc09bcc28ef794"
+        );
+        template.items[0].alt_names.as_mut().unwrap().reverse();
+        assert_eq!(template.compute_content_hash(), hash);
+        template.items[0].alt_names = Some(vec![]);
+        assert_ne!(template.compute_content_hash(), hash);
+    }
+
+    #[test]
+    fn test_sync_with_template() {
+        let mut template = make_template();
+        let mut populated = Tierlist::from_template(template.clone());
+        populated.items_to_tiers[0].1 = "S".to_string();
+        populated.items_to_tiers[1].1 = "Z".to_string();
+
+        // Drop C, add D and only allow S
+        template.items.pop();
+        template.items.push(TierlistItem {
+            name: "D".to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        });
+        template.tier_definitions = Some(vec!["S".into()]);
+        populated.sync_with_template(&template);
+
+        assert!(populated
+            .clone()
+            .validate_against_template(template.clone()));
+        let tiers: Vec<_> = populated
+            .items_to_tiers
+            .iter()
+            .map(|i| (i.0.name.as_str(), i.1.as_str()))
+            .collect();
+        assert_eq!(tiers, vec![("A", "S"), ("B", ""), ("D", "")]);
+    }
+
+    #[test]
+    fn test_record_revision() {
+        let mut populated = Tierlist::from_template(make_template());
+        let mut block = mock_env().block;
+        for revision in 1..=25 {
+            block.height += 1;
+            populated.record_revision(revision, &block);
+        }
+        assert_eq!(populated.revisions.len(), MAX_REVISIONS);
+        assert_eq!(populated.revisions[0].revision, 6);
+        assert_eq!(populated.revisions[MAX_REVISIONS - 1].height, block.height);
+    }
+
+    #[test]
+    fn test_tier_spread() {
+        let counts = |pairs: &[(&str, u64)]| -> Vec<(String, u64)> {
+            pairs.iter().map(|(t, c)| (t.to_string(), *c)).collect()
+        };
+
+        assert_eq!(tier_spread(&[]), Decimal::zero());
+        // Everyone agrees
+        assert_eq!(tier_spread(&counts(&[("S", 7)])), Decimal::zero());
+        // Perfectly split between two and four tiers
+        assert_eq!(
+            tier_spread(&counts(&[("S", 3), ("F", 3)])),
+            Decimal::percent(50)
+        );
+        assert_eq!(
+            tier_spread(&counts(&[("S", 2), ("A", 2), ("B", 2), ("F", 2)])),
+            Decimal::percent(75)
+        );
+        // Mostly agreeing
+        assert_eq!(
+            tier_spread(&counts(&[("S", 9), ("F", 1)])),
+            Decimal::percent(10)
+        );
+    }
+
+    #[test]
+    fn test_compare_tierlists() {
+        let template = make_template();
+        let mut a = Tierlist::from_template(template.clone());
+        let mut b = Tierlist::from_template(template.clone());
+        let mut ranked = template.clone();
+        ranked.tier_definitions = Some(vec!["S".into(), "A".into(), "B".into()]);
+
+        a.items_to_tiers[0].1 = "S".to_string();
+        b.items_to_tiers[0].1 = "S".to_string();
+        a.items_to_tiers[1].1 = "S".to_string();
+        b.items_to_tiers[1].1 = "B".to_string();
+        a.items_to_tiers[2].1 = "A".to_string();
+        b.items_to_tiers.pop();
+
+        let (items, agreement) = compare_tierlists(&a, &b, &ranked);
+        let summary: Vec<_> = items
+            .iter()
+            .map(|c| (c.item.as_str(), c.agree, c.distance))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("A", true, Some(0)),
+                ("B", false, Some(2)),
+                ("C", false, None)
+            ]
+        );
+        assert_eq!(items[2].tier_b, None);
+        assert_eq!(agreement, Decimal::from_ratio(1u64, 3u64));
+
+        // Free-form tiers have no distance
+        let (items, _) = compare_tierlists(&a, &b, &template);
+        assert!(items.iter().all(|c| c.distance.is_none()));
+
+        let (items, agreement) = compare_tierlists(&a, &a, &ranked);
+        assert!(items.iter().all(|c| c.agree));
+        assert_eq!(agreement, Decimal::one());
+    }
+
+    #[test]
+    fn test_load_baseline_template() {
+        // Templates stored before any of the optional fields existed
+        let json = br#"{"id":3,"title":"Fruit","items":[{"name":"A","image_url":null}],"creator":"addr1"}"#;
+        let template: TierlistTemplate = from_slice(json).unwrap();
+        assert_eq!(template.id, 3);
+        assert_eq!(template.items[0].order, 0);
+        assert!(template.submissions_open);
+        assert_eq!(template.tier_definitions, None);
+        assert_eq!(template.max_submissions, None);
+    }
+
+    #[test]
+    fn test_raw_layout() {
+        // Other contracts read these keys directly, changing them breaks those readers
+        assert_eq!(raw::TIERLIST_TEMPLATES_NAMESPACE, "tierlist_templates");
+        assert_eq!(raw::TIERLISTS_NAMESPACE, "tierlists");
+
+        let mut expected = vec![0, 18];
+        expected.extend_from_slice(b"tierlist_templates");
+        expected.extend_from_slice(&7u64.to_be_bytes());
+        assert_eq!(raw::template_key(7), expected);
+
+        let mut expected = vec![0, 9];
+        expected.extend_from_slice(b"tierlists");
+        expected.extend_from_slice(&[0, 5]);
+        expected.extend_from_slice(b"addr1");
+        expected.extend_from_slice(&7u64.to_be_bytes());
+        assert_eq!(raw::tierlist_key(&Addr::unchecked(ADDR1), 7), expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let template = make_template();
+        let tierlist = Tierlist::from_template(template.clone());
+        assert_eq!(tierlist.validate(&template), Ok(()));
+
+        let mut wrong = tierlist.clone();
+        wrong.template_id = 3;
+        assert_eq!(
+            wrong.validate(&template),
+            Err(TierlistValidationError::WrongTemplate {
+                expected: 0,
+                actual: 3
+            })
+        );
+
+        let mut unknown = tierlist.clone();
+        unknown.items_to_tiers[2].0.name = "Z".to_string();
+        assert_eq!(
+            unknown.validate(&template),
+            Err(TierlistValidationError::UnknownItem {
+                name: "Z".to_string()
+            })
+        );
+
+        let mut duplicate = tierlist.clone();
+        duplicate.items_to_tiers[2].0.name = "A".to_string();
+        assert_eq!(
+            duplicate.validate(&template),
+            Err(TierlistValidationError::DuplicateItem {
+                name: "A".to_string()
+            })
+        );
+
+        let mut missing = tierlist.clone();
+        missing.items_to_tiers.pop();
+        assert_eq!(
+            missing.validate(&template),
+            Err(TierlistValidationError::MissingItem {
+                name: "C".to_string()
+            })
+        );
+
+        let mut mismatch = tierlist;
+        mismatch.items_to_tiers[1].0.image_url = Some("b.png".to_string());
+        assert_eq!(
+            mismatch.validate(&template),
+            Err(TierlistValidationError::ItemMismatch {
+                name: "B".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_allocate_template_id() {
+        let mut storage = MockStorage::new();
+        assert_eq!(allocate_template_id(&mut storage).unwrap(), 0);
+        assert_eq!(allocate_template_id(&mut storage).unwrap(), 1);
+        assert_eq!(NEXT_ID.load(&storage).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_allocate_template_id_overflow() {
+        let mut storage = MockStorage::new();
+        NEXT_ID.save(&mut storage, &(u64::MAX - 1)).unwrap();
+        assert_eq!(allocate_template_id(&mut storage).unwrap(), u64::MAX - 1);
+        let err = allocate_template_id(&mut storage).unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+        // Nothing was reserved by the failed attempt
+        assert_eq!(NEXT_ID.load(&storage).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_load_or_err() {
+        let mut storage = MockStorage::new();
+        let template = TierlistTemplate {
+            id: 0,
+            title: "Tierlist".to_string(),
+            items: make_items(),
+            creator: Addr::unchecked(ADDR1),
+            tier_definitions: None,
+            max_submissions: None,
+            submissions_open: true,
+            sealed_at: None,
+            expires_at: None,
+            category: None,
+            language: None,
+            archived: false,
+            forked_from: None,
+            source: None,
+            created_at: None,
+            strict_uniqueness: false,
+            visibility: TemplateVisibility::Public,
+            content_hash: Binary::default(),
+        };
+        let id = allocate_template_id(&mut storage).unwrap();
+        TIERLIST_TEMPLATES
+            .save(&mut storage, id, &template)
+            .unwrap();
+        allocate_template_id(&mut storage).unwrap();
+
+        assert_eq!(load_template_or_err(&storage, 0).unwrap(), template);
+        // Id 1 was assigned but never saved, as if deleted
+        let err = load_template_or_err(&storage, 1).unwrap_err();
+        assert!(matches!(err, ContractError::TemplateNotFound { id: 1 }));
+        let err = load_template_or_err(&storage, 2).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTemplateId { id: 2 }));
+
+        let address = Addr::unchecked(ADDR1);
+        let err = load_tierlist_or_err(&storage, &address, 0).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TierlistNotFound { template_id: 0, .. }
+        ));
+        let tierlist = Tierlist::from_template(template);
+        TIERLISTS
+            .save(&mut storage, (&address, 0), &tierlist)
+            .unwrap();
+        assert_eq!(
+            load_tierlist_or_err(&storage, &address, 0).unwrap(),
+            tierlist
+        );
+    }
+
+    #[test]
+    fn test_tier_def_from_plain_name() {
+        // Templates stored before tier metadata existed hold plain names
+        let tiers: Vec<TierDef> =
+            from_slice(br##"["S", {"name": "A", "color": "#ff7f7f"}]"##).unwrap();
+        assert_eq!(
+            tiers,
+            vec![
+                TierDef::from("S"),
+                TierDef {
+                    name: "A".to_string(),
+                    color: Some("#ff7f7f".to_string()),
+                    emoji: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tier_rank() {
+        let mut template = make_template();
+        assert_eq!(template.tier_rank("S"), None);
+        assert_eq!(template.compare_tiers("S", "A"), Ordering::Greater);
+
+        template.tier_definitions = Some(vec!["S".into(), "A".into(), "B".into()]);
+        assert_eq!(template.tier_rank("S"), Some(0));
+        assert_eq!(template.tier_rank("B"), Some(2));
+        assert_eq!(template.tier_rank("Z"), None);
+        assert_eq!(template.compare_tiers("S", "A"), Ordering::Less);
+        assert_eq!(template.compare_tiers("B", "A"), Ordering::Greater);
+        assert_eq!(template.compare_tiers("A", "A"), Ordering::Equal);
+        // Undeclared tiers rank below declared ones, by name among themselves
+        assert_eq!(template.compare_tiers("Z", "B"), Ordering::Greater);
+        assert_eq!(template.compare_tiers("X", "Y"), Ordering::Less);
+    }
 }
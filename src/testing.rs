@@ -0,0 +1,236 @@
+//! cw-multi-test helpers for testing against the contract, enabled by the `test-utils`
+//! feature.
+//!
+//! ```ignore
+//! let mut suite = SuiteBuilder::new().with_admin("admin").build();
+//! suite.contract.create_template(&mut suite.app, "creator", "Fruit", items)?;
+//! let template = suite.contract.query_template(&suite.app, 0)?;
+//! ```
+
+use crate::contract::{execute, instantiate, query};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, TemplateResponse, TierlistResponse};
+use crate::state::{Tierlist, TierlistItem, TierlistTemplate};
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Empty, StdResult};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+
+pub fn contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+/// Address of an instantiated contract with typed messages
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierlistContract(pub Addr);
+
+impl TierlistContract {
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    pub fn execute(&self, app: &mut App, sender: &str, msg: &ExecuteMsg) -> AnyResult<AppResponse> {
+        app.execute_contract(Addr::unchecked(sender), self.0.clone(), msg, &[])
+    }
+
+    pub fn create_template(
+        &self,
+        app: &mut App,
+        sender: &str,
+        title: &str,
+        items: Vec<TierlistItem>,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::CreateTemplate {
+            title: title.to_string(),
+            items,
+            tier_definitions: None,
+            max_submissions: None,
+            language: None,
+            strict_uniqueness: None,
+            visibility: None,
+        };
+        self.execute(app, sender, &msg)
+    }
+
+    /// Saves, replacing any existing tierlist
+    pub fn save_tierlist(
+        &self,
+        app: &mut App,
+        sender: &str,
+        tierlist: Tierlist,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::SaveTierlist {
+            tierlist,
+            replace: None,
+            idempotency_key: None,
+            force: None,
+        };
+        self.execute(app, sender, &msg)
+    }
+
+    pub fn query<T: serde::de::DeserializeOwned>(&self, app: &App, msg: &QueryMsg) -> StdResult<T> {
+        app.wrap().query_wasm_smart(self.0.clone(), msg)
+    }
+
+    pub fn query_template(&self, app: &App, id: u64) -> StdResult<Option<TierlistTemplate>> {
+        let msg = QueryMsg::Template { id, strict: None };
+        let res: TemplateResponse = self.query(app, &msg)?;
+        Ok(res.template)
+    }
+
+    pub fn query_tierlist(&self, app: &App, address: &str, id: u64) -> StdResult<Option<Tierlist>> {
+        let msg = QueryMsg::Tierlist {
+            address: address.to_string(),
+            id,
+            strict: None,
+        };
+        let res: TierlistResponse = self.query(app, &msg)?;
+        Ok(res.tierlist)
+    }
+}
+
+/// An app with one instantiated contract
+pub struct Suite {
+    pub app: App,
+    pub contract: TierlistContract,
+    pub admin: Addr,
+}
+
+/// Builds a `Suite`, the instantiate options default to the contract's defaults
+pub struct SuiteBuilder {
+    admin: String,
+    require_template_tiers: bool,
+    hide_creators: bool,
+    max_tierlist_bytes: Option<u64>,
+}
+
+impl Default for SuiteBuilder {
+    fn default() -> Self {
+        SuiteBuilder {
+            admin: "admin".to_string(),
+            require_template_tiers: false,
+            hide_creators: false,
+            max_tierlist_bytes: None,
+        }
+    }
+}
+
+impl SuiteBuilder {
+    pub fn new() -> Self {
+        SuiteBuilder::default()
+    }
+
+    pub fn with_admin(mut self, admin: &str) -> Self {
+        self.admin = admin.to_string();
+        self
+    }
+
+    pub fn with_require_template_tiers(mut self, require: bool) -> Self {
+        self.require_template_tiers = require;
+        self
+    }
+
+    pub fn with_hide_creators(mut self, hide: bool) -> Self {
+        self.hide_creators = hide;
+        self
+    }
+
+    pub fn with_max_tierlist_bytes(mut self, max: u64) -> Self {
+        self.max_tierlist_bytes = Some(max);
+        self
+    }
+
+    pub fn build(self) -> Suite {
+        let mut app = App::default();
+        let code_id = app.store_code(contract());
+        let admin = Addr::unchecked(self.admin);
+        let msg = InstantiateMsg {
+            admin_address: admin.to_string(),
+            require_template_tiers: self.require_template_tiers,
+            hide_creators: self.hide_creators,
+            max_tierlist_bytes: self.max_tierlist_bytes,
+        };
+        let addr = app
+            .instantiate_contract(code_id, admin.clone(), &msg, &[], "tierlist", None)
+            .unwrap();
+        Suite {
+            app,
+            contract: TierlistContract(addr),
+            admin,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{ContractError, OwnershipError};
+    use crate::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+    use crate::state::{Tierlist, TierlistItem};
+    use crate::testing::SuiteBuilder;
+
+    fn item(name: &str) -> TierlistItem {
+        TierlistItem {
+            name: name.to_string(),
+            image_url: None,
+            order: 0,
+            alt_names: None,
+        }
+    }
+
+    #[test]
+    fn test_suite_create_and_save() {
+        let mut suite = SuiteBuilder::new().with_admin("admin").build();
+        let contract = suite.contract.clone();
+        contract
+            .create_template(
+                &mut suite.app,
+                "creator",
+                "Fruit",
+                vec![item("A"), item("B")],
+            )
+            .unwrap();
+        let template = contract.query_template(&suite.app, 0).unwrap().unwrap();
+        assert_eq!(template.title, "Fruit");
+        assert_eq!(template.creator.as_str(), "creator");
+
+        let tierlist = Tierlist {
+            template_id: 0,
+            revisions: vec![],
+            locked: false,
+            items_to_tiers: vec![(item("A"), "S".to_string()), (item("B"), "A".to_string())],
+        };
+        contract
+            .save_tierlist(&mut suite.app, "user", tierlist)
+            .unwrap();
+        let saved = contract
+            .query_tierlist(&suite.app, "user", 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved.items_to_tiers[0].1, "S");
+        assert_eq!(
+            contract.query_tierlist(&suite.app, "other", 0).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_suite_admin() {
+        let mut suite = SuiteBuilder::new()
+            .with_admin("admin")
+            .with_hide_creators(true)
+            .build();
+        let contract = suite.contract.clone();
+        let config: ConfigResponse = contract.query(&suite.app, &QueryMsg::Config {}).unwrap();
+        assert_eq!(config.admin_address, Some(suite.admin.to_string()));
+        assert!(config.hide_creators);
+
+        // Contract errors come back through the app
+        let msg = ExecuteMsg::BanAddress {
+            address: "user".to_string(),
+        };
+        let err = contract.execute(&mut suite.app, "user", &msg).unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::Ownership(OwnershipError::NotOwner {})
+        ));
+        contract.execute(&mut suite.app, "admin", &msg).unwrap();
+    }
+}
@@ -0,0 +1,287 @@
+//! End-to-end scenarios run through cw-multi-test, covering message routing, address
+//! validation and bank transfers that the unit tests with mock dependencies skip.
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Empty, Uint128};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use cw_tierlist::contract::{execute, instantiate, query};
+use cw_tierlist::msg::{
+    ExecuteMsg, InstantiateMsg, QueryMsg, TemplateCursor, TemplateResponse, TierlistResponse,
+};
+use cw_tierlist::state::{Tierlist, TierlistItem, TierlistTemplate};
+use cw_tierlist::{ContractError, OwnershipError};
+
+const ADMIN: &str = "admin";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+const CAROL: &str = "carol";
+const DENOM: &str = "ujuno";
+
+fn contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+fn item(name: &str) -> TierlistItem {
+    TierlistItem {
+        name: name.to_string(),
+        image_url: None,
+        order: 0,
+        alt_names: None,
+    }
+}
+
+fn items() -> Vec<TierlistItem> {
+    vec![item("Apple"), item("Banana"), item("Cherry")]
+}
+
+fn tierlist(template_id: u64, tiers: [&str; 3]) -> Tierlist {
+    Tierlist {
+        template_id,
+        revisions: vec![],
+        locked: false,
+        items_to_tiers: items()
+            .into_iter()
+            .zip(tiers.iter())
+            .map(|(item, tier)| (item, tier.to_string()))
+            .collect(),
+    }
+}
+
+/// An app where bob holds 100 ujuno, with the contract instantiated by the admin
+fn setup() -> (App, Addr) {
+    let mut app = App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(BOB), coins(100, DENOM))
+            .unwrap();
+    });
+    let code_id = app.store_code(contract());
+    let msg = InstantiateMsg {
+        admin_address: ADMIN.to_string(),
+        require_template_tiers: false,
+        hide_creators: false,
+        max_tierlist_bytes: None,
+    };
+    let addr = app
+        .instantiate_contract(code_id, Addr::unchecked(ADMIN), &msg, &[], "tierlist", None)
+        .unwrap();
+    (app, addr)
+}
+
+fn exec(
+    app: &mut App,
+    contract: &Addr,
+    sender: &str,
+    msg: &ExecuteMsg,
+    funds: &[Coin],
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(Addr::unchecked(sender), contract.clone(), msg, funds)
+}
+
+fn create_template(app: &mut App, contract: &Addr, sender: &str, title: &str) {
+    let msg = ExecuteMsg::CreateTemplate {
+        title: title.to_string(),
+        items: items(),
+        tier_definitions: None,
+        max_submissions: None,
+        language: None,
+        strict_uniqueness: None,
+        visibility: None,
+    };
+    exec(app, contract, sender, &msg, &[]).unwrap();
+}
+
+fn save(
+    app: &mut App,
+    contract: &Addr,
+    sender: &str,
+    tierlist: Tierlist,
+    funds: &[Coin],
+) -> anyhow::Result<AppResponse> {
+    let msg = ExecuteMsg::SaveTierlist {
+        tierlist,
+        replace: None,
+        idempotency_key: None,
+        force: None,
+    };
+    exec(app, contract, sender, &msg, funds)
+}
+
+fn query_template(app: &App, contract: &Addr, id: u64) -> Option<TierlistTemplate> {
+    let msg = QueryMsg::Template { id, strict: None };
+    let res: TemplateResponse = app.wrap().query_wasm_smart(contract, &msg).unwrap();
+    res.template
+}
+
+fn query_tierlist(app: &App, contract: &Addr, address: &str, id: u64) -> Option<Tierlist> {
+    let msg = QueryMsg::Tierlist {
+        address: address.to_string(),
+        id,
+        strict: None,
+    };
+    let res: TierlistResponse = app.wrap().query_wasm_smart(contract, &msg).unwrap();
+    res.tierlist
+}
+
+fn balance(app: &App, address: &str) -> Uint128 {
+    app.wrap().query_balance(address, DENOM).unwrap().amount
+}
+
+#[test]
+fn test_template_lifecycle() {
+    let (mut app, contract) = setup();
+
+    // Templates from several accounts share one id sequence
+    let templates = [(ALICE, "Fruit"), (BOB, "Snacks"), (ALICE, "Drinks")];
+    for (sender, title) in templates.iter() {
+        create_template(&mut app, &contract, sender, title);
+    }
+    for (id, (sender, title)) in templates.iter().enumerate() {
+        let template = query_template(&app, &contract, id as u64).unwrap();
+        assert_eq!(template.title, *title);
+        assert_eq!(template.creator, Addr::unchecked(*sender));
+    }
+
+    // Only the creator may edit
+    let patch = ExecuteMsg::PatchTemplate {
+        id: 0,
+        title: Some("Fruits".to_string()),
+        items: None,
+    };
+    let err = exec(&mut app, &contract, BOB, &patch, &[]).unwrap_err();
+    assert!(matches!(
+        err.downcast().unwrap(),
+        ContractError::Unauthorized { .. }
+    ));
+    exec(&mut app, &contract, ALICE, &patch, &[]).unwrap();
+    assert_eq!(query_template(&app, &contract, 0).unwrap().title, "Fruits");
+
+    // Deleted templates are gone from queries
+    let delete = ExecuteMsg::DeleteTemplate { id: 2 };
+    exec(&mut app, &contract, ALICE, &delete, &[]).unwrap();
+    assert_eq!(query_template(&app, &contract, 2), None);
+}
+
+#[test]
+fn test_tierlist_lifecycle() {
+    let (mut app, contract) = setup();
+    create_template(&mut app, &contract, ALICE, "Fruit");
+    let id = 0;
+
+    save(&mut app, &contract, BOB, tierlist(id, ["S", "A", "B"]), &[]).unwrap();
+    save(
+        &mut app,
+        &contract,
+        CAROL,
+        tierlist(id, ["B", "B", "S"]),
+        &[],
+    )
+    .unwrap();
+    let saved = query_tierlist(&app, &contract, BOB, id).unwrap();
+    assert_eq!(saved.items_to_tiers[0].1, "S");
+
+    // Re-saving replaces the stored tierlist
+    save(&mut app, &contract, BOB, tierlist(id, ["F", "A", "B"]), &[]).unwrap();
+    let saved = query_tierlist(&app, &contract, BOB, id).unwrap();
+    assert_eq!(saved.items_to_tiers[0].1, "F");
+
+    // Queries normalize the address casing
+    let msg = QueryMsg::TierlistsByAddress {
+        address: BOB.to_uppercase(),
+        start_after: None,
+        limit: None,
+    };
+    let tierlists: Vec<(u64, Tierlist)> = app.wrap().query_wasm_smart(&contract, &msg).unwrap();
+    assert_eq!(tierlists.len(), 1);
+    assert_eq!(tierlists[0].0, id);
+
+    // Only the admin may delete another user's tierlist
+    let delete = ExecuteMsg::AdminDeleteTierlist {
+        address: BOB.to_string(),
+        template_id: id,
+    };
+    let err = exec(&mut app, &contract, CAROL, &delete, &[]).unwrap_err();
+    assert!(matches!(
+        err.downcast().unwrap(),
+        ContractError::Ownership(OwnershipError::NotOwner {})
+    ));
+    exec(&mut app, &contract, ADMIN, &delete, &[]).unwrap();
+    assert_eq!(query_tierlist(&app, &contract, BOB, id), None);
+    assert!(query_tierlist(&app, &contract, CAROL, id).is_some());
+}
+
+#[test]
+fn test_template_pagination() {
+    let (mut app, contract) = setup();
+    for i in 0..7 {
+        let sender = if i % 2 == 0 { ALICE } else { CAROL };
+        create_template(&mut app, &contract, sender, &format!("Template {}", i));
+    }
+
+    let mut seen = vec![];
+    let mut start_after = None;
+    loop {
+        let msg = QueryMsg::Templates {
+            start_after,
+            limit: Some(3),
+            open_only: None,
+            category: None,
+            language: None,
+            include_unspecified: None,
+            sort: None,
+        };
+        let page: Vec<(u64, TierlistTemplate)> =
+            app.wrap().query_wasm_smart(&contract, &msg).unwrap();
+        match page.last() {
+            Some((id, _)) => start_after = Some(TemplateCursor::Id(*id)),
+            None => break,
+        }
+        assert!(page.len() <= 3);
+        seen.extend(page.into_iter().map(|(id, _)| id));
+    }
+    assert_eq!(seen, (0..7).collect::<Vec<u64>>());
+}
+
+#[test]
+fn test_template_fee_transfers() {
+    let (mut app, contract) = setup();
+    let msg = ExecuteMsg::CreateTemplateWithFee {
+        title: "Paid".to_string(),
+        items: items(),
+        tier_definitions: None,
+        max_submissions: None,
+        language: None,
+        fee_token: DENOM.to_string(),
+        fee_amount: Uint128::new(10),
+        visibility: None,
+    };
+    exec(&mut app, &contract, CAROL, &msg, &[]).unwrap();
+
+    // Underpaying fails and moves no funds
+    let err = save(
+        &mut app,
+        &contract,
+        BOB,
+        tierlist(0, ["S", "A", "B"]),
+        &coins(5, DENOM),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.downcast().unwrap(),
+        ContractError::InsufficientFee { .. }
+    ));
+    assert_eq!(balance(&app, BOB), Uint128::new(100));
+    assert_eq!(balance(&app, CAROL), Uint128::zero());
+
+    // The fee is forwarded to the template creator
+    save(
+        &mut app,
+        &contract,
+        BOB,
+        tierlist(0, ["S", "A", "B"]),
+        &[coin(10, DENOM)],
+    )
+    .unwrap();
+    assert_eq!(balance(&app, BOB), Uint128::new(90));
+    assert_eq!(balance(&app, CAROL), Uint128::new(10));
+    assert_eq!(balance(&app, contract.as_str()), Uint128::zero());
+}